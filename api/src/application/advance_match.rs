@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use crate::domain::{Game, EventStore, EventStoreError, GameEvent, GameState, Match, MatchError, MatchRepository, MatchRepositoryError};
+
+pub struct AdvanceMatch {
+    pub event_store: Arc<dyn EventStore + Send + Sync>,
+    pub match_repo: Arc<dyn MatchRepository + Send + Sync>
+}
+
+impl AdvanceMatch {
+    pub fn new(event_store: Arc<dyn EventStore + Send + Sync>, match_repo: Arc<dyn MatchRepository + Send + Sync>) -> Self {
+        Self {
+            event_store,
+            match_repo
+        }
+    }
+
+    /// Credits a completed game to its match and starts the match's next
+    /// game. Games outside a match, or not yet completed, are left alone.
+    /// The match is saved before the next game is, so a game credited
+    /// meanwhile is refused as a conflict without leaving a game behind.
+    pub async fn execute(&self, game: &Game) -> Result<Option<Match>, AdvanceMatchError> {
+        if game.state() != GameState::Completed {
+            return Ok(None);
+        }
+        let Some(game_match) = self.match_repo.find_by_game(game.id()).await? else {
+            return Ok(None);
+        };
+        if game_match.current_game() != Some(game.id()) {
+            return Ok(Some(game_match));
+        }
+
+        let (game_match, next) = game_match.record_game(game)?;
+        self.match_repo.save(game_match.clone()).await?;
+        if let Some(next) = next {
+            tracing::info!("Match {} continues with game {}", game_match.id(), next.id());
+            self.event_store.append(next.id(), 0, GameEvent::stream(&next)).await?;
+        }
+
+        Ok(Some(game_match))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AdvanceMatchError {
+    #[error("Match error: {0}")]
+    InvalidMatch(#[from] MatchError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
+    #[error("Match repository error: {0}")]
+    MatchRepositoryError(#[from] MatchRepositoryError)
+}
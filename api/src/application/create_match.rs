@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use crate::application::{StartNewGame, StartNewGameError};
+use crate::domain::{EventStore, EventStoreError, GameEvent, Match, MatchError, MatchRepository, MatchRepositoryError, Player, PlayerProfile, RuleSet};
+
+pub struct CreateMatch {
+    pub event_store: Arc<dyn EventStore + Send + Sync>,
+    pub match_repo: Arc<dyn MatchRepository + Send + Sync>
+}
+
+impl CreateMatch {
+    pub fn new(event_store: Arc<dyn EventStore + Send + Sync>, match_repo: Arc<dyn MatchRepository + Send + Sync>) -> Self {
+        Self {
+            event_store,
+            match_repo
+        }
+    }
+
+    /// Starts a match and its first game.
+    pub async fn execute(&self, dealer: Player, rule_set: RuleSet, seating: Vec<(Player, PlayerProfile)>, wins_required: u32) -> Result<Match, CreateMatchError> {
+        let game = StartNewGame::new_game(dealer, rule_set, seating)?;
+        let game_match = Match::new(&game, wins_required)?;
+
+        self.event_store.append(game.id(), 0, GameEvent::stream(&game)).await?;
+        self.match_repo.save(game_match.clone()).await?;
+
+        Ok(game_match)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateMatchError {
+    #[error(transparent)]
+    StartNewGameError(#[from] StartNewGameError),
+    #[error("Match error: {0}")]
+    InvalidMatch(#[from] MatchError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
+    #[error("Match repository error: {0}")]
+    MatchRepositoryError(#[from] MatchRepositoryError)
+}
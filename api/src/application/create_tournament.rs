@@ -0,0 +1,39 @@
+use std::sync::Arc;
+use crate::domain::{EventStore, EventStoreError, GameEvent, PlayerProfile, RuleSet, Tournament, TournamentError, TournamentRepository, TournamentRepositoryError};
+
+pub struct CreateTournament {
+    pub event_store: Arc<dyn EventStore + Send + Sync>,
+    pub tournament_repo: Arc<dyn TournamentRepository + Send + Sync>
+}
+
+impl CreateTournament {
+    pub fn new(event_store: Arc<dyn EventStore + Send + Sync>, tournament_repo: Arc<dyn TournamentRepository + Send + Sync>) -> Self {
+        Self {
+            event_store,
+            tournament_repo
+        }
+    }
+
+    /// Schedules the tournament and saves the games of every round.
+    pub async fn execute(&self, name: String, players: Vec<PlayerProfile>, rule_set: RuleSet, rounds: Option<usize>) -> Result<Tournament, CreateTournamentError> {
+        let (tournament, games) = Tournament::new(name, players, rule_set, rounds)?;
+
+        for game in games {
+            self.event_store.append(game.id(), 0, GameEvent::stream(&game)).await?;
+        }
+        self.tournament_repo.save(tournament.clone()).await?;
+
+        tracing::info!("Scheduled tournament {} over {} rounds", tournament.id(), tournament.rounds().len());
+        Ok(tournament)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum CreateTournamentError {
+    #[error("Tournament error: {0}")]
+    InvalidTournament(#[from] TournamentError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
+    #[error("Tournament repository error: {0}")]
+    TournamentRepositoryError(#[from] TournamentRepositoryError)
+}
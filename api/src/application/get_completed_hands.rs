@@ -15,17 +15,18 @@ impl GetCompletedHands {
     }
     pub async fn execute(&self, game_id: GameId) -> Result<Vec<Hand>, GetCompletedHandsError> {
         let game = self.game_repo.find_by_id(game_id).await?;
-        
-        if let Some(game) = game {
-            return Ok(game.completed_hands());
+
+        match game {
+            Some(game) => Ok(game.completed_hands()),
+            None => Err(GetCompletedHandsError::GameNotFound(game_id))
         }
-        
-        Ok(Vec::new())
     }
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum GetCompletedHandsError {
+    #[error("Game not found: {0}")]
+    GameNotFound(GameId),
     #[error("Game Repository Error: {0}")]
     GameRepoError(#[from] GameRepositoryError)
 }
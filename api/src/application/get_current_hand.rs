@@ -15,12 +15,11 @@ impl GetCurrentHand {
     }
     pub async fn execute(&self, game_id: GameId) -> Result<Option<Hand>, GetCurrentHandError> {
         let game = self.game_repo.find_by_id(game_id).await?;
-        
-        if let Some(game) = game {
-            return Ok(game.current_hand())
-        };
-        
-        Ok(None)
+
+        match game {
+            Some(game) => Ok(game.current_hand()),
+            None => Err(GetCurrentHandError::GameNotFound(game_id))
+        }
     }
 }
 
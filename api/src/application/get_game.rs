@@ -0,0 +1,42 @@
+use std::sync::Arc;
+use crate::domain::{EventStore, EventStoreError, Game, GameAction, GameError, GameId, GameRepository, GameRepositoryError};
+
+pub struct GetGame {
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub event_store: Arc<dyn EventStore + Send + Sync>
+}
+
+impl GetGame {
+    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>, event_store: Arc<dyn EventStore + Send + Sync>) -> Self {
+        Self {
+            game_repo: repo,
+            event_store
+        }
+    }
+
+    pub async fn execute(&self, game_id: GameId) -> Result<Game, GetGameError> {
+        self.game_repo
+            .find_by_id(game_id)
+            .await?
+            .ok_or(GetGameError::GameNotFound(game_id))
+    }
+
+    /// The game as it stood when hand `hand_number` was dealt or, given
+    /// `after`, once that hand's last `after` action was taken.
+    pub async fn as_of(&self, game_id: GameId, hand_number: usize, after: Option<GameAction>) -> Result<Game, GetGameError> {
+        let events = self.event_store.load(game_id).await?;
+        Ok(Game::as_of(&events, hand_number, after)?)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetGameError {
+    #[error("Game not found: {0}")]
+    GameNotFound(GameId),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] GameRepositoryError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
+    #[error("Game error: {0}")]
+    GameError(#[from] GameError)
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+use crate::domain::{GameRepository, GameRepositoryError, Leaderboard, LeaderboardKind, LeaderboardSort};
+
+pub struct GetLeaderboard {
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>
+}
+
+impl GetLeaderboard {
+    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>) -> Self {
+        Self {
+            game_repo: repo
+        }
+    }
+
+    pub async fn execute(&self, kind: LeaderboardKind, sort: LeaderboardSort) -> Result<Leaderboard, GetLeaderboardError> {
+        let games = self.game_repo.find_all().await?;
+
+        Ok(Leaderboard::build(&games, kind).sorted(sort))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetLeaderboardError {
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] GameRepositoryError)
+}
@@ -0,0 +1,46 @@
+use std::sync::Arc;
+use crate::domain::{Game, GameRepository, GameRepositoryError, Match, MatchId, MatchRepository, MatchRepositoryError};
+
+pub struct GetMatch {
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub match_repo: Arc<dyn MatchRepository + Send + Sync>
+}
+
+impl GetMatch {
+    pub fn new(game_repo: Arc<dyn GameRepository + Send + Sync>, match_repo: Arc<dyn MatchRepository + Send + Sync>) -> Self {
+        Self {
+            game_repo,
+            match_repo
+        }
+    }
+
+    pub async fn execute(&self, match_id: MatchId) -> Result<Match, GetMatchError> {
+        self.match_repo
+            .find_by_id(match_id)
+            .await?
+            .ok_or(GetMatchError::MatchNotFound(match_id))
+    }
+
+    /// The games of the match in the order they were played.
+    pub async fn games(&self, match_id: MatchId) -> Result<Vec<Game>, GetMatchError> {
+        let game_match = self.execute(match_id).await?;
+
+        let mut games = Vec::new();
+        for game_id in game_match.games() {
+            if let Some(game) = self.game_repo.find_by_id(*game_id).await? {
+                games.push(game);
+            }
+        }
+        Ok(games)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetMatchError {
+    #[error("Match not found: {0}")]
+    MatchNotFound(MatchId),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] GameRepositoryError),
+    #[error("Match repository error: {0}")]
+    MatchRepositoryError(#[from] MatchRepositoryError)
+}
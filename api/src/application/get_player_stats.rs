@@ -0,0 +1,38 @@
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use crate::domain::{GameRepository, GameRepositoryError, StatsReport};
+
+pub struct GetPlayerStats {
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>
+}
+
+impl GetPlayerStats {
+    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>) -> Self {
+        Self {
+            game_repo: repo
+        }
+    }
+
+    /// Tallies the scored hands of every game started between `from` and
+    /// `to`, either bound being optional.
+    pub async fn execute(&self, from: Option<DateTime<Utc>>, to: Option<DateTime<Utc>>) -> Result<StatsReport, GetPlayerStatsError> {
+        if let (Some(from), Some(to)) = (from, to) && from > to {
+            return Err(GetPlayerStatsError::InvalidDateRange(from, to));
+        }
+
+        let games = self.game_repo.find_all().await?;
+        let in_range = games.iter().filter(|game| {
+            from.is_none_or(|from| game.created_at() >= from) && to.is_none_or(|to| game.created_at() <= to)
+        });
+
+        Ok(StatsReport::tally(in_range))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetPlayerStatsError {
+    #[error("Invalid date range: {0} is after {1}")]
+    InvalidDateRange(DateTime<Utc>, DateTime<Utc>),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] GameRepositoryError)
+}
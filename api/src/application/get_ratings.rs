@@ -0,0 +1,24 @@
+use std::sync::Arc;
+use crate::domain::{RatingRepository, RatingRepositoryError, Ratings};
+
+pub struct GetRatings {
+    pub rating_repo: Arc<dyn RatingRepository + Send + Sync>
+}
+
+impl GetRatings {
+    pub fn new(repo: Arc<dyn RatingRepository + Send + Sync>) -> Self {
+        Self {
+            rating_repo: repo
+        }
+    }
+
+    pub async fn execute(&self) -> Result<Ratings, GetRatingsError> {
+        Ok(self.rating_repo.load().await?)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetRatingsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] RatingRepositoryError)
+}
@@ -1,6 +1,7 @@
+use std::collections::{BTreeMap, HashMap};
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use crate::domain::{GameId, GameRepository, GameRepositoryError};
+use crate::domain::{GameId, GameRepository, GameRepositoryError, Partnership, Player, TeamId};
 use crate::infrastructure::InMemoryGameRepository;
 
 pub struct GetRunningTotal {
@@ -18,7 +19,17 @@ impl GetRunningTotal {
         
         if let Some(game) = game {
             let (us, them) = game.running_totals();
-            Ok(RunningTotal { us, them })
+            let by_player = match game.rule_set().table.is_individual() {
+                true => game.player_totals(),
+                false => HashMap::new(),
+            };
+            Ok(RunningTotal {
+                us,
+                them,
+                by_player,
+                by_team: game.team_totals(),
+                teams: game.rule_set().table.teams().to_vec(),
+            })
         } else {
             Err(GetRunningTotalError::GameNotFound)
         }
@@ -36,6 +47,10 @@ pub enum GetRunningTotalError {
 
 pub struct RunningTotal {
     pub us: i32,
-    pub them: i32
+    pub them: i32,
+    /// Per-player totals at tables where everyone scores for themselves.
+    pub by_player: HashMap<Player, i32>,
+    pub by_team: BTreeMap<TeamId, i32>,
+    pub teams: Vec<Partnership>,
 }
 
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+use crate::domain::{GameId, GameRepository, GameRepositoryError, Hand};
+
+pub struct GetTimeline {
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>
+}
+
+impl GetTimeline {
+    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>) -> Self {
+        Self {
+            game_repo: repo
+        }
+    }
+
+    /// Every scored hand of the game in order, including one not yet
+    /// followed by a new deal, with the running totals after it.
+    pub async fn execute(&self, game_id: GameId) -> Result<Vec<TimelineEntry>, GetTimelineError> {
+        let game = self.game_repo
+            .find_by_id(game_id)
+            .await?
+            .ok_or(GetTimelineError::GameNotFound(game_id))?;
+
+        Ok(game.scored_hands()
+            .into_iter()
+            .zip(game.running_totals_by_hand())
+            .enumerate()
+            .map(|(index, (hand, (us, them)))| TimelineEntry { hand_number: index + 1, hand, us, them })
+            .collect())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetTimelineError {
+    #[error("Game not found: {0}")]
+    GameNotFound(GameId),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] GameRepositoryError)
+}
+
+/// One line of the score sheet: a scored hand and the Us and Them
+/// running totals once it was scored.
+pub struct TimelineEntry {
+    pub hand_number: usize,
+    pub hand: Hand,
+    pub us: i32,
+    pub them: i32,
+}
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::domain::{GameRepository, GameRepositoryError, Standing, Tournament, TournamentId, TournamentRepository, TournamentRepositoryError};
+
+pub struct GetTournament {
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub tournament_repo: Arc<dyn TournamentRepository + Send + Sync>
+}
+
+impl GetTournament {
+    pub fn new(game_repo: Arc<dyn GameRepository + Send + Sync>, tournament_repo: Arc<dyn TournamentRepository + Send + Sync>) -> Self {
+        Self {
+            game_repo,
+            tournament_repo
+        }
+    }
+
+    pub async fn execute(&self, tournament_id: TournamentId) -> Result<Tournament, GetTournamentError> {
+        self.tournament_repo
+            .find_by_id(tournament_id)
+            .await?
+            .ok_or(GetTournamentError::TournamentNotFound(tournament_id))
+    }
+
+    /// Standings from the tournament's games as currently stored.
+    pub async fn standings(&self, tournament_id: TournamentId) -> Result<Vec<Standing>, GetTournamentError> {
+        let tournament = self.execute(tournament_id).await?;
+
+        let mut games = HashMap::new();
+        for game_id in tournament.game_ids() {
+            if let Some(game) = self.game_repo.find_by_id(game_id).await? {
+                games.insert(game_id, game);
+            }
+        }
+        Ok(tournament.standings(&games))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum GetTournamentError {
+    #[error("Tournament not found: {0}")]
+    TournamentNotFound(TournamentId),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] GameRepositoryError),
+    #[error("Tournament repository error: {0}")]
+    TournamentRepositoryError(#[from] TournamentRepositoryError)
+}
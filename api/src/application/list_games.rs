@@ -0,0 +1,94 @@
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use crate::domain::{EventStore, EventStoreError, Game, GameRepository, GameRepositoryError, GameState};
+
+/// The time games are listed by, newest first unless asked otherwise.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameSort {
+    #[default]
+    UpdatedAt,
+    CreatedAt,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    Asc,
+    #[default]
+    Desc,
+}
+
+pub struct ListGames {
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub event_store: Arc<dyn EventStore + Send + Sync>
+}
+
+impl ListGames {
+    pub const MAX_PER_PAGE: usize = 100;
+
+    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>, event_store: Arc<dyn EventStore + Send + Sync>) -> Self {
+        Self {
+            game_repo: repo,
+            event_store
+        }
+    }
+
+    /// One page of the games in `state`, or of every game, sorted by `sort`.
+    /// Pages count from one.
+    pub async fn execute(&self, state: Option<GameState>, sort: GameSort, order: SortOrder, page: usize, per_page: usize) -> Result<GamePage, ListGamesError> {
+        if page == 0 {
+            return Err(ListGamesError::InvalidPage("page counts from 1".to_string()));
+        }
+        if per_page == 0 || per_page > Self::MAX_PER_PAGE {
+            return Err(ListGamesError::InvalidPage(format!("per_page must be between 1 and {}", Self::MAX_PER_PAGE)));
+        }
+
+        let mut games = Vec::new();
+        for game in self.game_repo.find_all().await? {
+            if state.is_some_and(|state| game.state() != state) {
+                continue;
+            }
+            let updated_at = self.event_store.updated_at(game.id()).await?;
+            games.push(GameListing { game, updated_at });
+        }
+
+        games.sort_by_key(|listing| match sort {
+            GameSort::UpdatedAt => listing.updated_at,
+            GameSort::CreatedAt => listing.game.created_at(),
+        });
+        if order == SortOrder::Desc {
+            games.reverse();
+        }
+
+        let total = games.len();
+        let games = games.into_iter().skip((page - 1) * per_page).take(per_page).collect();
+
+        Ok(GamePage { games, page, per_page, total })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ListGamesError {
+    #[error("Invalid page: {0}")]
+    InvalidPage(String),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] GameRepositoryError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError)
+}
+
+/// A game in a listing, with when it last changed.
+pub struct GameListing {
+    pub game: Game,
+    pub updated_at: DateTime<Utc>,
+}
+
+pub struct GamePage {
+    pub games: Vec<GameListing>,
+    pub page: usize,
+    pub per_page: usize,
+    /// Games matching the filter across every page.
+    pub total: usize,
+}
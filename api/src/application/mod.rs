@@ -1,19 +1,68 @@
 pub mod start_new_game;
 pub mod start_new_hand;
 pub mod record_bid;
+pub mod record_call;
+pub mod record_all_pass;
 pub mod declare_trump;
 pub mod record_meld;
 pub mod record_tricks;
+pub mod record_counters;
+pub mod start_play;
+pub mod play_card;
+pub mod record_widow;
+pub mod substitute_player;
+pub mod undo_action;
+pub mod redo_action;
+pub mod edit_completed_hand;
 pub mod get_completed_hands;
 pub mod get_current_hand;
+pub mod get_game;
+pub mod get_leaderboard;
+pub mod get_player_stats;
 pub mod get_running_total;
+pub mod get_timeline;
+pub mod list_games;
+pub mod get_ratings;
+pub mod recompute_ratings;
+pub mod update_ratings;
+pub mod create_match;
+pub mod advance_match;
+pub mod settle_game_outcome;
+pub mod get_match;
+pub mod create_tournament;
+pub mod get_tournament;
 
 pub use start_new_game::{StartNewGame, StartNewGameError};
 pub use start_new_hand::{StartNewHand, StartNewHandError};
 pub use record_bid::{RecordBid, RecordBidError};
+pub use record_call::{RecordCall, RecordCallError};
+pub use record_all_pass::{RecordAllPass, RecordAllPassError};
+pub use undo_action::{UndoAction, UndoActionError};
+pub use redo_action::{RedoAction, RedoActionError};
+pub use edit_completed_hand::{EditCompletedHand, EditCompletedHandError};
 pub use declare_trump::{DeclareTrump, DeclareTrumpError};
 pub use record_meld::{RecordMeld, RecordMeldError};
 pub use record_tricks::{RecordTricks, RecordTricksError};
+pub use crate::domain::{MeldEntry, TricksEntry};
+pub use record_counters::{RecordCounters, RecordCountersError};
+pub use start_play::{StartPlay, StartPlayError};
+pub use play_card::{PlayCard, PlayCardError};
+pub use record_widow::{RecordWidow, RecordWidowError};
+pub use substitute_player::{SubstitutePlayer, SubstitutePlayerError};
 pub use get_completed_hands::{GetCompletedHands, GetCompletedHandsError};
 pub use get_current_hand::{GetCurrentHand, GetCurrentHandError};
+pub use get_game::{GetGame, GetGameError};
+pub use get_leaderboard::{GetLeaderboard, GetLeaderboardError};
+pub use get_player_stats::{GetPlayerStats, GetPlayerStatsError};
 pub use get_running_total::{GetRunningTotal, GetRunningTotalError, RunningTotal};
+pub use get_timeline::{GetTimeline, GetTimelineError, TimelineEntry};
+pub use list_games::{GameListing, GamePage, GameSort, ListGames, ListGamesError, SortOrder};
+pub use get_ratings::{GetRatings, GetRatingsError};
+pub use recompute_ratings::{RecomputeRatings, RecomputeRatingsError};
+pub use update_ratings::UpdateRatings;
+pub use create_match::{CreateMatch, CreateMatchError};
+pub use advance_match::AdvanceMatch;
+pub use settle_game_outcome::{SettleGameOutcome, SettleGameOutcomeError};
+pub use get_match::{GetMatch, GetMatchError};
+pub use create_tournament::{CreateTournament, CreateTournamentError};
+pub use get_tournament::{GetTournament, GetTournamentError};
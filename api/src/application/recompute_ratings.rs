@@ -0,0 +1,43 @@
+use std::sync::Arc;
+use crate::domain::{GameRepository, GameRepositoryError, RatingRepository, RatingRepositoryError, Ratings};
+
+pub struct RecomputeRatings {
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub rating_repo: Arc<dyn RatingRepository + Send + Sync>
+}
+
+impl RecomputeRatings {
+    pub fn new(game_repo: Arc<dyn GameRepository + Send + Sync>, rating_repo: Arc<dyn RatingRepository + Send + Sync>) -> Self {
+        Self {
+            game_repo,
+            rating_repo
+        }
+    }
+
+    /// Throws the ratings away and replays every stored game in the order
+    /// the games were completed. Ratings changed meanwhile are replayed
+    /// again, so a game rated during the replay is not lost.
+    pub async fn execute(&self) -> Result<Ratings, RecomputeRatingsError> {
+        loop {
+            let current = self.rating_repo.load().await?;
+            let games = self.game_repo.find_all().await?;
+            let ratings = Ratings::replay(&games).with_version(current.version());
+
+            match self.rating_repo.save(ratings.clone()).await {
+                Ok(()) => {
+                    tracing::info!("Recomputed ratings from {} games", games.len());
+                    return Ok(ratings);
+                },
+                Err(RatingRepositoryError::Conflict { .. }) => tracing::info!("Ratings changed during the replay, replaying again"),
+            }
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RecomputeRatingsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] GameRepositoryError),
+    #[error("Rating repository error: {0}")]
+    RatingRepositoryError(#[from] RatingRepositoryError)
+}
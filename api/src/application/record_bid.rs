@@ -1,16 +1,18 @@
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use crate::domain::{Game, GameError, GameId, GameRepository, GameRepositoryError, Player};
+use crate::domain::{Game, GameError, GameId, EventStore, EventStoreError, GameRepository, GameRepositoryError, Player};
 use crate::infrastructure::InMemoryGameRepository;
 
 pub struct RecordBid {
-    pub game_repo: Arc<dyn GameRepository + Send + Sync>
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub event_store: Arc<dyn EventStore + Send + Sync>
 }
 
 impl RecordBid {
-    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>) -> Self {
+    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>, event_store: Arc<dyn EventStore + Send + Sync>) -> Self {
         Self {
-            game_repo: repo
+            game_repo: repo,
+            event_store
         }
     }
 
@@ -19,7 +21,7 @@ impl RecordBid {
         match game {
             Some(game) => {
                 let game = game.record_bid(player, bid)?;
-                self.game_repo.save(game.clone()).await?;
+                self.event_store.append(game_id, game.version(), game.changes().to_vec()).await?;
                 Ok(game)
             },
             None => Err(RecordBidError::GameNotFound(game_id))
@@ -33,6 +35,8 @@ pub enum RecordBidError {
     GameNotFound(GameId),
     #[error("Repository error: {0}")]
     RepositoryError(#[from] GameRepositoryError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
     #[error("Game error: {0}")]
     GameError(#[from] GameError)
 }
@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use crate::application::SettleGameOutcome;
+use crate::domain::{Game, GameError, GameId, EventStore, EventStoreError, GameRepository, GameRepositoryError, Team};
+
+pub struct RecordCounters {
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub event_store: Arc<dyn EventStore + Send + Sync>,
+    pub outcome: Option<Arc<SettleGameOutcome>>
+}
+
+impl RecordCounters {
+    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>, event_store: Arc<dyn EventStore + Send + Sync>) -> Self {
+        Self {
+            game_repo: repo,
+            event_store,
+            outcome: None
+        }
+    }
+
+    pub fn with_outcome(mut self, outcome: Arc<SettleGameOutcome>) -> Self {
+        self.outcome = Some(outcome);
+        self
+    }
+
+    pub async fn execute(&self, game_id: GameId, us: u32, them: u32, last_trick: Team) -> Result<Game, RecordCountersError> {
+        let game = self.game_repo.find_by_id(game_id).await?;
+        match game {
+            Some(before) => {
+                let game = before.record_counters(us, them, last_trick)?;
+                self.event_store.append(game_id, game.version(), game.changes().to_vec()).await?;
+                if let Some(outcome) = &self.outcome {
+                    outcome.execute(&before, &game).await;
+                }
+                Ok(game)
+            },
+            None => Err(RecordCountersError::GameNotFound(game_id))
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RecordCountersError {
+    #[error("Game not found: {0}")]
+    GameNotFound(GameId),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] GameRepositoryError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
+    #[error("Game error: {0}")]
+    GameError(#[from] GameError)
+}
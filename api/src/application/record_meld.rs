@@ -1,25 +1,36 @@
 use std::sync::Arc;
-use tokio::sync::Mutex;
-use crate::domain::{Game, GameError, GameId, GameRepository, GameRepositoryError};
-use crate::infrastructure::InMemoryGameRepository;
+use crate::application::SettleGameOutcome;
+use crate::domain::{Game, GameError, GameId, EventStore, EventStoreError, GameRepository, GameRepositoryError, MeldEntry};
 
 pub struct RecordMeld {
-    pub game_repo: Arc<dyn GameRepository + Send + Sync>
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub event_store: Arc<dyn EventStore + Send + Sync>,
+    pub outcome: Option<Arc<SettleGameOutcome>>
 }
 
 impl RecordMeld {
-    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>) -> Self {
+    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>, event_store: Arc<dyn EventStore + Send + Sync>) -> Self {
         Self {
-            game_repo: repo
+            game_repo: repo,
+            event_store,
+            outcome: None
         }
     }
 
-    pub async fn execute(&self, game_id: GameId, us: u32, them: u32) -> Result<Game, RecordMeldError> {
+    pub fn with_outcome(mut self, outcome: Arc<SettleGameOutcome>) -> Self {
+        self.outcome = Some(outcome);
+        self
+    }
+
+    pub async fn execute(&self, game_id: GameId, entry: MeldEntry) -> Result<Game, RecordMeldError> {
         let game = self.game_repo.find_by_id(game_id).await?;
         match game {
-            Some(game) => {
-                let game = game.record_meld(us, them)?;
-                self.game_repo.save(game.clone()).await?;
+            Some(before) => {
+                let game = before.record_meld_entry(entry)?;
+                self.event_store.append(game_id, game.version(), game.changes().to_vec()).await?;
+                if let Some(outcome) = &self.outcome {
+                    outcome.execute(&before, &game).await;
+                }
                 Ok(game)
             },
             None => Err(RecordMeldError::GameNotFound)
@@ -33,7 +44,8 @@ pub enum RecordMeldError {
     GameNotFound,
     #[error("Repository error: {0}")]
     RepositoryError(#[from] GameRepositoryError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
     #[error("Game error: {0}")]
     GameError(#[from] GameError)
 }
-
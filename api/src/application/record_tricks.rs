@@ -1,25 +1,36 @@
 use std::sync::Arc;
-use tokio::sync::Mutex;
-use crate::domain::{Game, GameError, GameId, GameRepository, GameRepositoryError};
-use crate::infrastructure::InMemoryGameRepository;
+use crate::application::SettleGameOutcome;
+use crate::domain::{Game, GameError, GameId, EventStore, EventStoreError, GameRepository, GameRepositoryError, TricksEntry};
 
 pub struct RecordTricks {
-    pub game_repo: Arc<dyn GameRepository + Send + Sync>
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub event_store: Arc<dyn EventStore + Send + Sync>,
+    pub outcome: Option<Arc<SettleGameOutcome>>
 }
 
 impl RecordTricks {
-    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>) -> Self {
+    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>, event_store: Arc<dyn EventStore + Send + Sync>) -> Self {
         Self {
-            game_repo: repo
+            game_repo: repo,
+            event_store,
+            outcome: None
         }
     }
 
-    pub async fn execute(&self, game_id: GameId, us: u32, them: u32) -> Result<Game, RecordTricksError> {
+    pub fn with_outcome(mut self, outcome: Arc<SettleGameOutcome>) -> Self {
+        self.outcome = Some(outcome);
+        self
+    }
+
+    pub async fn execute(&self, game_id: GameId, entry: TricksEntry) -> Result<Game, RecordTricksError> {
         let maybe_game = self.game_repo.find_by_id(game_id).await?;
         match maybe_game {
             Some(existing_game) => {
-                let updated_game = existing_game.record_tricks(us, them)?;
-                self.game_repo.save(updated_game.clone()).await?;
+                let updated_game = existing_game.record_tricks_entry(entry)?;
+                self.event_store.append(game_id, updated_game.version(), updated_game.changes().to_vec()).await?;
+                if let Some(outcome) = &self.outcome {
+                    outcome.execute(&existing_game, &updated_game).await;
+                }
                 
                 tracing::info!("Updated game: {:#?}", self.game_repo.find_by_id(game_id).await?);
                 Ok(updated_game)
@@ -35,6 +46,8 @@ pub enum RecordTricksError {
     GameNotFound,
     #[error("Repository error: {0}")]
     RepositoryError(#[from] GameRepositoryError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
     #[error("Game error: {0}")]
     GameError(#[from] GameError)
 }
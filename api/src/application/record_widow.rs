@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use crate::domain::{Card, Game, GameError, GameId, EventStore, EventStoreError, GameRepository, GameRepositoryError};
+
+pub struct RecordWidow {
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub event_store: Arc<dyn EventStore + Send + Sync>
+}
+
+impl RecordWidow {
+    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>, event_store: Arc<dyn EventStore + Send + Sync>) -> Self {
+        Self {
+            game_repo: repo,
+            event_store
+        }
+    }
+
+    pub async fn execute(&self, game_id: GameId, widow: Vec<Card>) -> Result<Game, RecordWidowError> {
+        let game = self.game_repo.find_by_id(game_id).await?;
+        match game {
+            Some(game) => {
+                let game = game.record_widow(widow)?;
+                self.event_store.append(game_id, game.version(), game.changes().to_vec()).await?;
+                Ok(game)
+            },
+            None => Err(RecordWidowError::GameNotFound(game_id))
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RecordWidowError {
+    #[error("Game not found: {0}")]
+    GameNotFound(GameId),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] GameRepositoryError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
+    #[error("Game error: {0}")]
+    GameError(#[from] GameError)
+}
@@ -0,0 +1,62 @@
+use std::sync::Arc;
+use crate::application::{AdvanceMatch, RecomputeRatings, UpdateRatings};
+use crate::domain::{Game, MatchError, MatchRepositoryError};
+
+/// What follows from a game being won: rating its players and moving its
+/// match on. Every use case that can complete a game, or change the result
+/// of one, hands the game before and after the change to `execute`.
+pub struct SettleGameOutcome {
+    pub ratings: Arc<UpdateRatings>,
+    pub recompute: Arc<RecomputeRatings>,
+    pub matches: Arc<AdvanceMatch>
+}
+
+impl SettleGameOutcome {
+    pub fn new(ratings: Arc<UpdateRatings>, recompute: Arc<RecomputeRatings>, matches: Arc<AdvanceMatch>) -> Self {
+        Self {
+            ratings,
+            recompute,
+            matches
+        }
+    }
+
+    /// Refuses a change that would reopen `before` or hand it to another
+    /// team once its match has counted the win. Call it before the change
+    /// is saved.
+    pub async fn check(&self, before: &Game, after: &Game) -> Result<(), SettleGameOutcomeError> {
+        if !after.changes_winner_of(before) {
+            return Ok(());
+        }
+        match self.matches.match_repo.find_by_game(before.id()).await? {
+            Some(game_match) if game_match.has_credited(before.id()) => Err(MatchError::GameCredited(before.id()).into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Settles the change from `before` to `after` as saved. A completed
+    /// game whose result or totals changed has its rating taken back by
+    /// replaying every game; a newly completed one is rated as it stands.
+    /// Failures are logged rather than returned: the change has already
+    /// been recorded.
+    pub async fn execute(&self, before: &Game, after: &Game) {
+        if after.revises_result_of(before) {
+            tracing::info!("Result of game {} changed, replaying ratings", after.id());
+            if let Err(error) = self.recompute.execute().await {
+                tracing::warn!("Could not recompute ratings after game {} changed: {}", after.id(), error);
+            }
+        } else if let Err(error) = self.ratings.execute(after).await {
+            tracing::warn!("Could not update ratings for game {}: {}", after.id(), error);
+        }
+        if let Err(error) = self.matches.execute(after).await {
+            tracing::warn!("Could not advance the match of game {}: {}", after.id(), error);
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SettleGameOutcomeError {
+    #[error("Match error: {0}")]
+    InvalidMatch(#[from] MatchError),
+    #[error("Match repository error: {0}")]
+    MatchRepositoryError(#[from] MatchRepositoryError)
+}
@@ -1,29 +1,45 @@
 use std::sync::Arc;
 use tokio::sync::Mutex;
-use crate::domain::{Game, GameRepository, GameRepositoryError, Player};
+use crate::domain::{Game, EventStore, EventStoreError, GameEvent, Player, PlayerProfile, Roster, RuleSet};
 
 pub struct StartNewGame {
-    pub game_repo: Arc<dyn GameRepository + Send + Sync>
+    pub event_store: Arc<dyn EventStore + Send + Sync>
 }
 
 impl StartNewGame {
-    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>) -> Self {
+    pub fn new(event_store: Arc<dyn EventStore + Send + Sync>) -> Self {
         Self {
-            game_repo: repo
+            event_store
         }
     }
 
-    pub async fn execute(&self, dealer: Player) -> Result<Game, StartNewGameError> {
-        
-        let game = Game::new(dealer);
-        self.game_repo.save(game.clone()).await?;
+    pub async fn execute(&self, dealer: Player, rule_set: RuleSet, seating: Vec<(Player, PlayerProfile)>) -> Result<Game, StartNewGameError> {
+        let game = Self::new_game(dealer, rule_set, seating)?;
+        self.event_store.append(game.id(), 0, GameEvent::stream(&game)).await?;
         
         Ok(game)
     }
+
+    /// Builds a game after checking its table and seating, without saving it.
+    pub fn new_game(dealer: Player, rule_set: RuleSet, seating: Vec<(Player, PlayerProfile)>) -> Result<Game, StartNewGameError> {
+        rule_set.validate().map_err(StartNewGameError::InvalidRuleSet)?;
+        rule_set.table.validate().map_err(StartNewGameError::InvalidTable)?;
+
+        let roster = Roster::new(seating);
+        roster.validate(&rule_set.table).map_err(StartNewGameError::InvalidSeating)?;
+
+        Ok(Game::new(dealer).with_rule_set(rule_set).with_roster(roster))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum StartNewGameError {
-    #[error("Game repository error: {0}")]
-    GameRepositoryError(#[from] GameRepositoryError)
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
+    #[error("Invalid rule set: {0}")]
+    InvalidRuleSet(String),
+    #[error("Invalid table: {0}")]
+    InvalidTable(String),
+    #[error("Invalid seating: {0}")]
+    InvalidSeating(String)
 }
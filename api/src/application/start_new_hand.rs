@@ -1,27 +1,30 @@
 use std::sync::Arc;
-use tokio::sync::Mutex;
-use crate::application::RecordTricksError;
-use crate::domain::{Game, GameError, GameId, GameRepository, GameRepositoryError};
+use crate::domain::{Game, GameError, GameId, EventStore, EventStoreError, GameRepository, GameRepositoryError};
 
 pub struct StartNewHand {
-    pub game_repo: Arc<dyn GameRepository + Send + Sync>
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub event_store: Arc<dyn EventStore + Send + Sync>
 }
 
 impl StartNewHand {
-    pub fn new(game_repo: Arc<dyn GameRepository + Send + Sync>) -> Self {
+    pub fn new(game_repo: Arc<dyn GameRepository + Send + Sync>, event_store: Arc<dyn EventStore + Send + Sync>) -> Self {
         Self {
              game_repo,
+             event_store,
         }
     }
     
-    pub async fn execute(&self, game_id: GameId) -> Result<Game, StartNewHandError>
+    pub async fn execute(&self, game_id: GameId, seed: Option<u64>) -> Result<Game, StartNewHandError>
     {
         let game = self.game_repo.find_by_id(game_id).await?;
         
         match game {
             Some(game) => {
-                let game = game.start_new_hand()?;
-                self.game_repo.save(game.clone()).await?;
+                let game = match seed {
+                    Some(seed) => game.start_new_seeded_hand(seed)?,
+                    None => game.start_new_hand()?,
+                };
+                self.event_store.append(game_id, game.version(), game.changes().to_vec()).await?;
                 Ok(game)
             },
             None => Err(StartNewHandError::GameNotFound(game_id))
@@ -37,6 +40,8 @@ pub enum StartNewHandError {
     GameNotFound(GameId),
     #[error("Repository error: {0}")]
     RepositoryError(#[from] GameRepositoryError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
     #[error("Game error: {0}")]
     GameError(#[from] GameError)
 }
\ No newline at end of file
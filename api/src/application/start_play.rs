@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::domain::{Card, Game, GameError, GameId, EventStore, EventStoreError, GameRepository, GameRepositoryError, Player};
+
+pub struct StartPlay {
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub event_store: Arc<dyn EventStore + Send + Sync>
+}
+
+impl StartPlay {
+    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>, event_store: Arc<dyn EventStore + Send + Sync>) -> Self {
+        Self {
+            game_repo: repo,
+            event_store
+        }
+    }
+
+    pub async fn execute(&self, game_id: GameId, hands: Option<HashMap<Player, Vec<Card>>>) -> Result<Game, StartPlayError> {
+        let game = self.game_repo.find_by_id(game_id).await?;
+        match game {
+            Some(game) => {
+                let game = game.start_play(hands)?;
+                self.event_store.append(game_id, game.version(), game.changes().to_vec()).await?;
+                Ok(game)
+            },
+            None => Err(StartPlayError::GameNotFound(game_id))
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum StartPlayError {
+    #[error("Game not found: {0}")]
+    GameNotFound(GameId),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] GameRepositoryError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
+    #[error("Game error: {0}")]
+    GameError(#[from] GameError)
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+use crate::domain::{Game, GameError, GameId, EventStore, EventStoreError, GameRepository, GameRepositoryError, Player, PlayerProfile};
+
+pub struct SubstitutePlayer {
+    pub game_repo: Arc<dyn GameRepository + Send + Sync>,
+    pub event_store: Arc<dyn EventStore + Send + Sync>
+}
+
+impl SubstitutePlayer {
+    pub fn new(repo: Arc<dyn GameRepository + Send + Sync>, event_store: Arc<dyn EventStore + Send + Sync>) -> Self {
+        Self {
+            game_repo: repo,
+            event_store
+        }
+    }
+
+    pub async fn execute(&self, game_id: GameId, seat: Player, player: PlayerProfile, from_hand: Option<usize>) -> Result<Game, SubstitutePlayerError> {
+        let game = self.game_repo.find_by_id(game_id).await?;
+        match game {
+            Some(game) => {
+                let game = game.substitute(seat, player, from_hand)?;
+                self.event_store.append(game_id, game.version(), game.changes().to_vec()).await?;
+                Ok(game)
+            },
+            None => Err(SubstitutePlayerError::GameNotFound(game_id))
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum SubstitutePlayerError {
+    #[error("Game not found: {0}")]
+    GameNotFound(GameId),
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] GameRepositoryError),
+    #[error("Event store error: {0}")]
+    EventStoreError(#[from] EventStoreError),
+    #[error("Game error: {0}")]
+    GameError(#[from] GameError)
+}
@@ -0,0 +1,37 @@
+use std::sync::Arc;
+use crate::domain::{Game, GameState, RatingRepository, RatingRepositoryError, Ratings};
+
+pub struct UpdateRatings {
+    pub rating_repo: Arc<dyn RatingRepository + Send + Sync>
+}
+
+impl UpdateRatings {
+    pub fn new(repo: Arc<dyn RatingRepository + Send + Sync>) -> Self {
+        Self {
+            rating_repo: repo
+        }
+    }
+
+    /// Rates `game` the first time it is seen completed. Ratings changed
+    /// by another game meanwhile are loaded again and `game` rated on top.
+    pub async fn execute(&self, game: &Game) -> Result<Ratings, UpdateRatingsError> {
+        loop {
+            let ratings = self.rating_repo.load().await?;
+            if game.state() != GameState::Completed || ratings.is_rated(game.id()) {
+                return Ok(ratings);
+            }
+
+            let ratings = ratings.rate(game);
+            match self.rating_repo.save(ratings.clone()).await {
+                Ok(()) => return Ok(ratings),
+                Err(RatingRepositoryError::Conflict { .. }) => tracing::info!("Ratings changed while rating game {}, trying again", game.id()),
+            }
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum UpdateRatingsError {
+    #[error("Repository error: {0}")]
+    RepositoryError(#[from] RatingRepositoryError)
+}
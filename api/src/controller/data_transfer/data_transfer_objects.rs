@@ -1,17 +1,137 @@
+use std::collections::{BTreeMap, HashMap};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
-use crate::application::RunningTotal;
-use crate::domain::{Game, Hand, Player, Suit, GameState};
+use crate::application::{GameListing, GamePage, GameSort, MeldEntry, RunningTotal, SortOrder, TimelineEntry, TricksEntry};
+use crate::domain::{AuctionCall, Call, Card, Game, GameAction, Hand, HandCorrection, HandEdit, MeldItem, Partnership, PlayedCard, Player, HeadToHead, Leaderboard, LeaderboardEntry, LeaderboardKind, LeaderboardSort, Match, MatchState, PlayerId, Round, Standing, TableAssignment, Tournament, PlayerProfile, PlayerStats, RatingSubject, Ratings, SeatAssignment, StatsReport, Suit, GameState, RuleSet, RuleSetPreset, Team, TeamId, Winner};
 use crate::domain::Player::South;
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct StartNewGameRequest {
     pub dealer: Player,
+    #[serde(default)]
+    pub rule_set: Option<RuleSetRequest>,
+    /// Teams for this game, replacing the rule set's default partnerships.
+    #[serde(default)]
+    pub partnerships: Option<Vec<Partnership>>,
+    /// Who sits in each seat when the game starts.
+    #[serde(default)]
+    pub players: Vec<SeatRequest>,
+}
+
+/// A player taking a seat. Without an id a new player profile is created.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SeatRequest {
+    pub seat: Player,
+    #[serde(default)]
+    pub id: Option<Uuid>,
+    pub name: String,
+}
+
+impl SeatRequest {
+    pub fn profile(&self) -> PlayerProfile {
+        let profile = PlayerProfile::new(self.name.clone());
+        match self.id {
+            Some(id) => profile.with_id(PlayerId(id)),
+            None => profile,
+        }
+    }
+}
+
+impl StartNewGameRequest {
+    pub fn rule_set(&self) -> RuleSet {
+        let rule_set = self.rule_set.clone().map(RuleSet::from).unwrap_or_default();
+        match self.partnerships.clone() {
+            Some(partnerships) => RuleSet {
+                table: rule_set.table.clone().with_partnerships(partnerships),
+                ..rule_set
+            },
+            None => rule_set,
+        }
+    }
+
+    pub fn seating(&self) -> Vec<(Player, PlayerProfile)> {
+        self.players.iter().map(|seat| (seat.seat, seat.profile())).collect()
+    }
+}
+
+/// A new match: the first game's setup and how many game wins take the
+/// match, two unless given.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateMatchRequest {
+    #[serde(flatten)]
+    pub game: StartNewGameRequest,
+    #[serde(default = "CreateMatchRequest::default_wins_required")]
+    pub wins_required: u32,
+}
+
+impl CreateMatchRequest {
+    fn default_wins_required() -> u32 {
+        2
+    }
+}
+
+/// A player entering a tournament. Without an id a new player profile is
+/// created.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EntrantRequest {
+    #[serde(default)]
+    pub id: Option<Uuid>,
+    pub name: String,
+}
+
+impl EntrantRequest {
+    pub fn profile(&self) -> PlayerProfile {
+        let profile = PlayerProfile::new(self.name.clone());
+        match self.id {
+            Some(id) => profile.with_id(PlayerId(id)),
+            None => profile,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateTournamentRequest {
+    pub name: String,
+    pub players: Vec<EntrantRequest>,
+    #[serde(default)]
+    pub rule_set: Option<RuleSetRequest>,
+    /// Fewer rounds than a full round robin.
+    #[serde(default)]
+    pub rounds: Option<usize>,
+}
+
+impl CreateTournamentRequest {
+    pub fn rule_set(&self) -> RuleSet {
+        self.rule_set.clone().map(RuleSet::from).unwrap_or_default()
+    }
+
+    pub fn entrants(&self) -> Vec<PlayerProfile> {
+        self.players.iter().map(EntrantRequest::profile).collect()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum RuleSetRequest {
+    Preset(RuleSetPreset),
+    Custom(RuleSet),
+}
+
+impl From<RuleSetRequest> for RuleSet {
+    fn from(value: RuleSetRequest) -> Self {
+        match value {
+            RuleSetRequest::Preset(preset) => RuleSet::preset(preset),
+            RuleSetRequest::Custom(rule_set) => rule_set,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct StartNewHandRequest {
-    pub(crate) game_id: Uuid
+    pub(crate) game_id: Uuid,
+    #[serde(default)]
+    pub(crate) seed: Option<u64>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -20,6 +140,25 @@ pub struct RecordBidRequest {
     pub bid: u32
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordCallRequest {
+    pub player: Player,
+    #[serde(default)]
+    pub bid: Option<u32>,
+    #[serde(default)]
+    pub pass: bool
+}
+
+impl RecordCallRequest {
+    pub fn call(&self) -> Option<Call> {
+        match (self.bid, self.pass) {
+            (Some(amount), false) => Some(Call::Bid(amount)),
+            (None, true) => Some(Call::Pass),
+            _ => None
+        }
+    }
+}
+
 #[derive(Debug, Clone, Deserialize)]
 pub struct DeclareTrumpRequest {
     pub trump: Suit
@@ -27,14 +166,109 @@ pub struct DeclareTrumpRequest {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct RecordMeldRequest {
-    pub us_meld: u32,
-    pub them_meld: u32,
+    #[serde(default)]
+    pub us_meld: Option<u32>,
+    #[serde(default)]
+    pub them_meld: Option<u32>,
+    #[serde(default)]
+    pub meld_cards: Option<HashMap<Player, Vec<Card>>>,
+    #[serde(default)]
+    pub meld_items: Option<HashMap<Player, Vec<MeldItem>>>,
+    #[serde(default)]
+    pub by_player: Option<HashMap<Player, u32>>,
+}
+
+impl RecordMeldRequest {
+    pub fn entry(self) -> Option<MeldEntry> {
+        match (self.meld_cards, self.by_player, self.us_meld, self.them_meld, self.meld_items) {
+            (Some(cards), None, None, None, None) => Some(MeldEntry::Cards(cards)),
+            (None, Some(by_player), None, None, None) => Some(MeldEntry::ByPlayer(by_player)),
+            (None, None, Some(us), Some(them), None) => Some(MeldEntry::Totals { us, them }),
+            (None, None, Some(us), Some(them), Some(items)) => Some(MeldEntry::Itemized { us, them, items }),
+            _ => None
+        }
+    }
+}
+
+/// Corrected values for a completed hand. Meld and tricks are keyed by team
+/// id; anything left out keeps the value the hand was scored with.
+#[derive(Debug, Clone, Deserialize)]
+pub struct EditHandRequest {
+    #[serde(default)]
+    pub bidder: Option<Player>,
+    #[serde(default)]
+    pub bid_amount: Option<u32>,
+    #[serde(default)]
+    pub trump: Option<Suit>,
+    #[serde(default)]
+    pub meld: BTreeMap<TeamId, u32>,
+    #[serde(default)]
+    pub tricks: BTreeMap<TeamId, u32>,
+}
+
+impl EditHandRequest {
+    pub fn correction(self) -> HandCorrection {
+        HandCorrection {
+            bidder: self.bidder,
+            bid_amount: self.bid_amount,
+            trump: self.trump,
+            meld: self.meld,
+            tricks: self.tricks,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct RecordTricksRequest {
-    pub us_tricks: u32,
-    pub them_tricks: u32
+    #[serde(default)]
+    pub us_tricks: Option<u32>,
+    #[serde(default)]
+    pub them_tricks: Option<u32>,
+    #[serde(default)]
+    pub by_player: Option<HashMap<Player, u32>>,
+}
+
+impl RecordTricksRequest {
+    pub fn entry(self) -> Option<TricksEntry> {
+        match (self.by_player, self.us_tricks, self.them_tricks) {
+            (Some(by_player), None, None) => Some(TricksEntry::ByPlayer(by_player)),
+            (None, Some(us), Some(them)) => Some(TricksEntry::Totals { us, them }),
+            _ => None
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordCountersRequest {
+    pub us_counters: u32,
+    pub them_counters: u32,
+    pub last_trick: Team,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct StartPlayRequest {
+    #[serde(default)]
+    pub hands: Option<HashMap<Player, Vec<Card>>>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordWidowRequest {
+    pub widow: Vec<Card>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct SubstitutePlayerRequest {
+    #[serde(flatten)]
+    pub player: SeatRequest,
+    /// First hand the substitute plays, by default the current hand.
+    #[serde(default)]
+    pub from_hand: Option<usize>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct PlayCardRequest {
+    pub player: Player,
+    pub card: Card,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -55,13 +289,455 @@ impl From<Vec<Hand>> for CompletedHandsResponse {
 pub struct RunningTotalResponse {
     us_total: i32,
     them_total: i32,
+    player_totals: HashMap<Player, i32>,
 }
 
 impl From<&RunningTotal> for RunningTotalResponse {
     fn from(value: &RunningTotal) -> Self {
         Self {
             us_total: value.us,
-            them_total: value.them
+            them_total: value.them,
+            player_totals: value.by_player.clone(),
+        }
+    }
+}
+
+/// Running totals keyed by team id, with the teams they belong to.
+#[derive(Debug, Clone, Serialize)]
+pub struct RunningTotalResponseV2 {
+    teams: Vec<Partnership>,
+    totals: BTreeMap<TeamId, i32>,
+}
+
+impl From<&RunningTotal> for RunningTotalResponseV2 {
+    fn from(value: &RunningTotal) -> Self {
+        Self {
+            teams: value.teams.clone(),
+            totals: value.by_team.clone(),
+        }
+    }
+}
+
+/// The point in a game's past to show: when hand `as_of_hand` was dealt or,
+/// with `after`, once that hand's last such action was taken.
+#[derive(Debug, Clone, Deserialize)]
+pub struct GameAsOfQuery {
+    #[serde(default)]
+    pub as_of_hand: Option<usize>,
+    #[serde(default)]
+    pub after: Option<GameAction>,
+}
+
+/// Which games to list and how: an optional state to keep, the time to
+/// sort by and one page of `per_page` games, counting from one.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ListGamesQuery {
+    #[serde(default)]
+    pub state: Option<GameState>,
+    #[serde(default)]
+    pub sort: GameSort,
+    #[serde(default)]
+    pub order: SortOrder,
+    #[serde(default = "ListGamesQuery::default_page")]
+    pub page: usize,
+    #[serde(default = "ListGamesQuery::default_per_page")]
+    pub per_page: usize,
+}
+
+impl ListGamesQuery {
+    fn default_page() -> usize {
+        1
+    }
+
+    fn default_per_page() -> usize {
+        20
+    }
+}
+
+/// What a list of games shows about each one.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameSummaryResponse {
+    game_id: Uuid,
+    game_state: GameState,
+    dealer: Player,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    hands_played: usize,
+    us_score: i32,
+    them_score: i32,
+    scores: BTreeMap<TeamId, i32>,
+    winner: Option<Winner>,
+    roster: Vec<SeatAssignment>,
+}
+
+impl From<&GameListing> for GameSummaryResponse {
+    fn from(listing: &GameListing) -> Self {
+        let game = &listing.game;
+        let (us_score, them_score) = game.running_totals();
+
+        Self {
+            game_id: game.id().0,
+            game_state: game.state(),
+            dealer: game.current_dealer(),
+            created_at: game.created_at(),
+            updated_at: listing.updated_at,
+            hands_played: game.completed_hands().len(),
+            us_score,
+            them_score,
+            scores: game.team_totals(),
+            winner: game.result().map(|result| result.winner),
+            roster: game.roster().assignments().to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GameListResponse {
+    games: Vec<GameSummaryResponse>,
+    page: usize,
+    per_page: usize,
+    total: usize,
+}
+
+impl From<&GamePage> for GameListResponse {
+    fn from(page: &GamePage) -> Self {
+        Self {
+            games: page.games.iter().map(GameSummaryResponse::from).collect(),
+            page: page.page,
+            per_page: page.per_page,
+            total: page.total,
+        }
+    }
+}
+
+/// Optional bounds on when the games counted in the stats were started.
+#[derive(Debug, Clone, Deserialize)]
+pub struct PlayerStatsQuery {
+    #[serde(default)]
+    pub from: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerStatsEntry {
+    hands_played: u32,
+    bids_won: u32,
+    bid_success_rate: Option<f64>,
+    average_bid: Option<f64>,
+    times_set: u32,
+    average_meld: Option<f64>,
+    games_played: u32,
+    games_won: u32,
+    favorite_trump: Option<Suit>,
+    no_marriage: u32,
+}
+
+impl From<&PlayerStats> for PlayerStatsEntry {
+    fn from(value: &PlayerStats) -> Self {
+        Self {
+            hands_played: value.hands_played,
+            bids_won: value.bids_won,
+            bid_success_rate: value.bid_success_rate(),
+            average_bid: value.average_bid(),
+            times_set: value.times_set,
+            average_meld: value.average_meld(),
+            games_played: value.games_played,
+            games_won: value.games_won,
+            favorite_trump: value.favorite_trump(),
+            no_marriage: value.no_marriage,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct NamedPlayerStatsResponse {
+    id: PlayerId,
+    name: String,
+    #[serde(flatten)]
+    stats: PlayerStatsEntry,
+}
+
+/// Stats per seat, and per person for games that recorded who sat where.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerStatsResponse {
+    by_seat: HashMap<Player, PlayerStatsEntry>,
+    players: Vec<NamedPlayerStatsResponse>,
+}
+
+impl From<&StatsReport> for PlayerStatsResponse {
+    fn from(value: &StatsReport) -> Self {
+        let mut players: Vec<NamedPlayerStatsResponse> = value.by_player
+            .iter()
+            .map(|(id, stats)| NamedPlayerStatsResponse {
+                id: *id,
+                name: value.players.get(id).map(|player| player.name.clone()).unwrap_or_default(),
+                stats: PlayerStatsEntry::from(stats),
+            })
+            .collect();
+        players.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            by_seat: value.by_seat.iter().map(|(seat, stats)| (*seat, PlayerStatsEntry::from(stats))).collect(),
+            players,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct LeaderboardQuery {
+    #[serde(default)]
+    pub by: LeaderboardKind,
+    #[serde(default)]
+    pub sort: LeaderboardSort,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct HeadToHeadResponse {
+    opponent: Vec<PlayerProfile>,
+    name: String,
+    wins: u32,
+    losses: u32,
+}
+
+impl From<&HeadToHead> for HeadToHeadResponse {
+    fn from(value: &HeadToHead) -> Self {
+        Self {
+            opponent: value.opponent.clone(),
+            name: value.opponent.iter().map(|player| player.name.as_str()).collect::<Vec<_>>().join(" & "),
+            wins: value.wins,
+            losses: value.losses,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LeaderboardEntryResponse {
+    rank: usize,
+    players: Vec<PlayerProfile>,
+    name: String,
+    games_played: u32,
+    wins: u32,
+    losses: u32,
+    win_rate: Option<f64>,
+    average_margin: Option<f64>,
+    head_to_head: Vec<HeadToHeadResponse>,
+}
+
+impl LeaderboardEntryResponse {
+    fn new(rank: usize, entry: &LeaderboardEntry) -> Self {
+        Self {
+            rank,
+            players: entry.players.clone(),
+            name: entry.name(),
+            games_played: entry.games_played,
+            wins: entry.wins,
+            losses: entry.losses,
+            win_rate: entry.win_rate(),
+            average_margin: entry.average_margin(),
+            head_to_head: entry.head_to_head.iter().map(HeadToHeadResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LeaderboardResponse {
+    by: LeaderboardKind,
+    sort: LeaderboardSort,
+    entries: Vec<LeaderboardEntryResponse>,
+}
+
+impl LeaderboardResponse {
+    pub fn new(query: &LeaderboardQuery, leaderboard: &Leaderboard) -> Self {
+        Self {
+            by: query.by,
+            sort: query.sort,
+            entries: leaderboard.entries()
+                .iter()
+                .enumerate()
+                .map(|(index, entry)| LeaderboardEntryResponse::new(index + 1, entry))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RatingPointResponse {
+    game_id: Uuid,
+    before: f64,
+    after: f64,
+    at: DateTime<Utc>,
+}
+
+/// The current rating of a player or partnership and how it got there.
+#[derive(Debug, Clone, Serialize)]
+pub struct RatingResponse {
+    players: Vec<PlayerProfile>,
+    name: String,
+    rating: f64,
+    history: Vec<RatingPointResponse>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RatingsResponse {
+    players: Vec<RatingResponse>,
+    partnerships: Vec<RatingResponse>,
+}
+
+impl From<&Ratings> for RatingsResponse {
+    fn from(value: &Ratings) -> Self {
+        let rating = |subject: &RatingSubject, ids: &[PlayerId]| {
+            let players: Vec<PlayerProfile> = ids.iter()
+                .map(|id| PlayerProfile::new(value.name(*id).unwrap_or_default()).with_id(*id))
+                .collect();
+            RatingResponse {
+                name: players.iter().map(|player| player.name.as_str()).collect::<Vec<_>>().join(" & "),
+                players,
+                rating: value.rating(subject),
+                history: value.history()
+                    .iter()
+                    .filter(|change| change.subject == *subject)
+                    .map(|change| RatingPointResponse { game_id: change.game_id.0, before: change.before, after: change.after, at: change.at })
+                    .collect(),
+            }
+        };
+
+        let mut players = Vec::new();
+        let mut partnerships = Vec::new();
+        for subject in value.ratings().keys() {
+            match subject {
+                RatingSubject::Player(id) => players.push(rating(subject, &[*id])),
+                RatingSubject::Partnership(ids) => partnerships.push(rating(subject, ids)),
+            }
+        }
+        let by_rating = |a: &RatingResponse, b: &RatingResponse| b.rating.total_cmp(&a.rating).then_with(|| a.name.cmp(&b.name));
+        players.sort_by(by_rating);
+        partnerships.sort_by(by_rating);
+
+        Self { players, partnerships }
+    }
+}
+
+/// The score of a match: games won by each team.
+#[derive(Debug, Clone, Serialize)]
+pub struct MatchResponse {
+    match_id: Uuid,
+    state: MatchState,
+    teams: Vec<Partnership>,
+    wins_required: u32,
+    wins: BTreeMap<TeamId, u32>,
+    games: Vec<Uuid>,
+    current_game: Option<Uuid>,
+    winner: Option<TeamId>,
+}
+
+impl From<&Match> for MatchResponse {
+    fn from(value: &Match) -> Self {
+        Self {
+            match_id: value.id().0,
+            state: value.state(),
+            teams: value.rule_set().table.teams().to_vec(),
+            wins_required: value.wins_required(),
+            wins: value.wins().clone(),
+            games: value.games().iter().map(|game_id| game_id.0).collect(),
+            current_game: match value.state() {
+                MatchState::InProgress => value.current_game().map(|game_id| game_id.0),
+                MatchState::Completed => None,
+            },
+            winner: value.winner(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TableAssignmentResponse {
+    table: usize,
+    us: Vec<PlayerProfile>,
+    them: Vec<PlayerProfile>,
+    game_id: Uuid,
+}
+
+impl From<&TableAssignment> for TableAssignmentResponse {
+    fn from(value: &TableAssignment) -> Self {
+        Self {
+            table: value.table,
+            us: value.us.clone(),
+            them: value.them.clone(),
+            game_id: value.game_id.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RoundResponse {
+    number: usize,
+    tables: Vec<TableAssignmentResponse>,
+}
+
+impl From<&Round> for RoundResponse {
+    fn from(value: &Round) -> Self {
+        Self {
+            number: value.number,
+            tables: value.tables.iter().map(TableAssignmentResponse::from).collect(),
+        }
+    }
+}
+
+/// A tournament's entrants and its schedule of rounds.
+#[derive(Debug, Clone, Serialize)]
+pub struct TournamentResponse {
+    tournament_id: Uuid,
+    name: String,
+    players: Vec<PlayerProfile>,
+    rule_set: RuleSet,
+    rounds: Vec<RoundResponse>,
+}
+
+impl From<&Tournament> for TournamentResponse {
+    fn from(value: &Tournament) -> Self {
+        Self {
+            tournament_id: value.id().0,
+            name: value.name().to_string(),
+            players: value.players().to_vec(),
+            rule_set: value.rule_set().clone(),
+            rounds: value.rounds().iter().map(RoundResponse::from).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct StandingResponse {
+    rank: usize,
+    player: PlayerProfile,
+    games_played: u32,
+    games_won: u32,
+    point_differential: i32,
+}
+
+impl StandingResponse {
+    pub fn new(rank: usize, standing: &Standing) -> Self {
+        Self {
+            rank,
+            player: standing.player.clone(),
+            games_played: standing.games_played,
+            games_won: standing.games_won,
+            point_differential: standing.point_differential,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AuctionCallResponse {
+    player: Player,
+    bid: Option<u32>,
+    pass: bool,
+}
+
+impl From<&AuctionCall> for AuctionCallResponse {
+    fn from(value: &AuctionCall) -> Self {
+        match value.call {
+            Call::Bid(amount) => Self { player: value.player, bid: Some(amount), pass: false },
+            Call::Pass => Self { player: value.player, bid: None, pass: true },
         }
     }
 }
@@ -76,11 +752,23 @@ pub struct HandResponse {
     trump: Option<Suit>,
     us_total: Option<i32>,
     them_total: Option<i32>,
+    player_totals: HashMap<Player, i32>,
     us_meld: Option<u32>,
     them_meld: Option<u32>,
     us_tricks: Option<u32>,
     them_tricks: Option<u32>,
     required_tricks: Option<u32>,
+    auction: Vec<AuctionCallResponse>,
+    meld_items: HashMap<Player, Vec<MeldItem>>,
+    player_meld: HashMap<Player, u32>,
+    player_tricks: HashMap<Player, u32>,
+    next_to_play: Option<Player>,
+    current_trick: Vec<PlayedCard>,
+    tricks_played: Option<usize>,
+    legal_plays: Vec<Card>,
+    seed: Option<u64>,
+    widow: Vec<Card>,
+    players: HashMap<Player, PlayerProfile>,
 }
 
 impl From<&Hand> for HandResponse {
@@ -94,32 +782,35 @@ impl From<&Hand> for HandResponse {
             trump: hand.trump(),
             us_total: Some(hand.us_total()),
             them_total: Some(hand.them_total()),
+            player_totals: match hand.rule_set().table.is_individual() {
+                true => hand.rule_set().table.seats().iter()
+                    .filter_map(|player| hand.rule_set().table.team_of(*player).and_then(|team| hand.score().totals.get(&team)).map(|total| (*player, *total)))
+                    .collect(),
+                false => HashMap::new(),
+            },
             us_meld: hand.us_meld(),
             them_meld: hand.them_meld(),
             us_tricks: hand.us_tricks(),
             them_tricks: hand.them_tricks(),
-            required_tricks: hand.tricks_to_save()
+            required_tricks: hand.tricks_to_save(),
+            auction: hand.auction().calls().iter().map(AuctionCallResponse::from).collect(),
+            meld_items: hand.meld_items().clone(),
+            player_meld: hand.player_meld().clone(),
+            player_tricks: hand.player_tricks().clone(),
+            next_to_play: hand.play().filter(|play| !play.is_finished()).map(|play| play.next_to_play()),
+            current_trick: hand.play().map(|play| play.current_trick().cards().to_vec()).unwrap_or_default(),
+            tricks_played: hand.play().map(|play| play.tricks_played()),
+            legal_plays: hand.legal_plays(),
+            seed: hand.seed(),
+            widow: hand.widow(),
+            players: hand.players().clone(),
         }
     }
 }
 
 impl From<Hand> for HandResponse {
     fn from(hand: Hand) -> Self {
-        HandResponse {
-            id: hand.id().0,
-            state: hand.state().to_string(),
-            dealer: Some(hand.dealer()),
-            bidder: hand.bidder(),
-            bid_amount: hand.bid_amount(),
-            trump: hand.trump(),
-            us_total: Some(hand.us_total()),
-            them_total: Some(hand.them_total()),
-            us_meld: hand.us_meld(),
-            them_meld: hand.them_meld(),
-            us_tricks: hand.us_tricks(),
-            them_tricks: hand.them_tricks(),
-            required_tricks: hand.tricks_to_save()
-        }
+        HandResponse::from(&hand)
     }
 }
 
@@ -134,15 +825,51 @@ impl From<Option<&Hand>> for HandResponse {
             trump: None,
             us_total: None,
             them_total: None,
+            player_totals: HashMap::new(),
             us_meld: None,
             them_meld: None,
             us_tricks: None,
             them_tricks: None,
             required_tricks: None,
+            auction: Vec::new(),
+            meld_items: HashMap::new(),
+            player_meld: HashMap::new(),
+            player_tricks: HashMap::new(),
+            next_to_play: None,
+            current_trick: Vec::new(),
+            tricks_played: None,
+            legal_plays: Vec::new(),
+            seed: None,
+            widow: Vec::new(),
+            players: HashMap::new(),
         })
     }
 }
 
+/// A completed hand on the score sheet, with whether the bid was made and
+/// the running totals after it.
+#[derive(Debug, Clone, Serialize)]
+pub struct TimelineEntryResponse {
+    hand_number: usize,
+    #[serde(flatten)]
+    hand: HandResponse,
+    bid_made: Option<bool>,
+    us_running_total: i32,
+    them_running_total: i32,
+}
+
+impl From<&TimelineEntry> for TimelineEntryResponse {
+    fn from(entry: &TimelineEntry) -> Self {
+        Self {
+            hand_number: entry.hand_number,
+            hand: HandResponse::from(&entry.hand),
+            bid_made: entry.hand.was_set().map(|set| !set),
+            us_running_total: entry.us,
+            them_running_total: entry.them,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameResponse {
     pub game_id: Uuid,
@@ -161,6 +888,16 @@ pub struct GameResponse {
     pub us_hand_score: Option<i32>,
     pub them_hand_score: Option<i32>,
     pub required_tricks: Option<u32>,
+    pub rule_set: Option<RuleSet>,
+    pub winner: Option<Team>,
+    pub winning_player: Option<Player>,
+    pub player_scores: HashMap<Player, i32>,
+    pub players: HashMap<Player, PlayerProfile>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// The action `POST /undo` would revert.
+    pub undo: Option<GameAction>,
+    /// The action `POST /redo` would reapply.
+    pub redo: Option<GameAction>,
 }
 
 impl From<&Game> for GameResponse {
@@ -189,6 +926,46 @@ impl From<&Game> for GameResponse {
             required_tricks,
             us_score: Some(us_score),
             them_score: Some(them_score),
+            rule_set: Some(game.rule_set().clone()),
+            winner: game.result().and_then(|result| match (result.winner, game.rule_set().table.us_them()) {
+                (Winner::Team(team), Some((us, _))) if team == us => Some(Team::Us),
+                (Winner::Team(team), Some((_, them))) if team == them => Some(Team::Them),
+                _ => None,
+            }),
+            winning_player: game.result().and_then(|result| match result.winner {
+                Winner::Player(player) => Some(player),
+                Winner::Team(_) => None,
+            }),
+            player_scores: match game.rule_set().table.is_individual() {
+                true => game.player_totals(),
+                false => HashMap::new(),
+            },
+            players: game.roster().seating(game.current_hand_number()),
+            completed_at: game.result().map(|result| result.completed_at),
+            undo: game.history().last_action(),
+            redo: game.history().next_redo(),
+        }
+    }
+}
+
+/// A game with the hands already played and the team in the lead.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameDetailResponse {
+    #[serde(flatten)]
+    pub game: GameResponse,
+    pub created_at: DateTime<Utc>,
+    /// The team ahead on the running totals, none while the lead is shared.
+    pub leader: Option<TeamId>,
+    pub completed_hands: Vec<HandResponse>,
+}
+
+impl From<&Game> for GameDetailResponse {
+    fn from(game: &Game) -> Self {
+        Self {
+            game: GameResponse::from(game),
+            created_at: game.created_at(),
+            leader: game.leading_team(),
+            completed_hands: game.completed_hands().iter().map(HandResponse::from).collect(),
         }
     }
 }
@@ -214,7 +991,74 @@ impl From<Option<&Game>> for GameResponse {
                 us_hand_score: None,
                 them_hand_score: None,
                 required_tricks: None,
+                rule_set: None,
+                winner: None,
+                winning_player: None,
+                player_scores: HashMap::new(),
+                players: HashMap::new(),
+                completed_at: None,
+                undo: None,
+                redo: None,
             }
         }
     }
 }
+
+/// The game with every score keyed by team id. `GameResponse` remains the
+/// Us/Them projection of the same game.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameResponseV2 {
+    pub game_id: Uuid,
+    pub game_state: GameState,
+    pub dealer: Player,
+    pub teams: Vec<Partnership>,
+    pub hand_state: Option<String>,
+    pub bidder: Option<Player>,
+    pub bidding_team: Option<TeamId>,
+    pub bid_amount: Option<u32>,
+    pub trump: Option<Suit>,
+    pub meld: BTreeMap<TeamId, u32>,
+    pub tricks: BTreeMap<TeamId, u32>,
+    pub hand_scores: BTreeMap<TeamId, i32>,
+    pub scores: BTreeMap<TeamId, i32>,
+    /// The team ahead on the running totals, none while the lead is shared.
+    pub leader: Option<TeamId>,
+    pub required_tricks: Option<u32>,
+    pub rule_set: RuleSet,
+    pub winner: Option<Winner>,
+    pub roster: Vec<SeatAssignment>,
+    pub completed_at: Option<DateTime<Utc>>,
+    /// Corrections made to completed hands, oldest first.
+    pub edits: Vec<HandEdit>,
+}
+
+impl From<&Game> for GameResponseV2 {
+    fn from(game: &Game) -> Self {
+        let hand = game.current_hand();
+        let score = hand.as_ref().map(|h| h.score().clone()).unwrap_or_default();
+        let table = &game.rule_set().table;
+
+        GameResponseV2 {
+            game_id: game.id().0,
+            game_state: game.state(),
+            dealer: game.current_dealer(),
+            teams: table.teams().to_vec(),
+            hand_state: hand.as_ref().map(|h| h.state().to_string()),
+            bidder: hand.as_ref().and_then(|h| h.bidder()),
+            bidding_team: hand.as_ref().and_then(|h| h.bidder()).and_then(|bidder| table.team_of(bidder)),
+            bid_amount: hand.as_ref().and_then(|h| h.bid_amount()),
+            trump: hand.as_ref().and_then(|h| h.trump()),
+            meld: score.meld,
+            tricks: score.tricks,
+            hand_scores: score.totals,
+            scores: game.team_totals(),
+            leader: game.leading_team(),
+            required_tricks: hand.as_ref().and_then(|h| h.tricks_to_save()),
+            rule_set: game.rule_set().clone(),
+            winner: game.result().map(|result| result.winner),
+            roster: game.roster().assignments().to_vec(),
+            completed_at: game.result().map(|result| result.completed_at),
+            edits: game.edits().to_vec(),
+        }
+    }
+}
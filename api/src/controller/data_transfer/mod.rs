@@ -1,6 +1,14 @@
 
 mod data_transfer_objects;
 
-pub use data_transfer_objects::{StartNewGameRequest, StartNewHandRequest, RecordBidRequest};
-pub use data_transfer_objects::{RecordMeldRequest, RecordTricksRequest, DeclareTrumpRequest};
+pub use data_transfer_objects::{StartNewGameRequest, StartNewHandRequest, RecordBidRequest, RecordCallRequest};
+pub use data_transfer_objects::{RecordMeldRequest, RecordTricksRequest, RecordCountersRequest, DeclareTrumpRequest};
+pub use data_transfer_objects::{RecordWidowRequest, StartPlayRequest, PlayCardRequest, SubstitutePlayerRequest};
 pub use data_transfer_objects::{CompletedHandsResponse, HandResponse, RunningTotalResponse,GameResponse};
+pub use data_transfer_objects::{GameAsOfQuery, GameResponseV2, RunningTotalResponseV2, TimelineEntryResponse};
+pub use data_transfer_objects::{GameDetailResponse, GameListResponse, ListGamesQuery};
+pub use data_transfer_objects::{PlayerStatsQuery, PlayerStatsResponse};
+pub use data_transfer_objects::{LeaderboardQuery, LeaderboardResponse, RatingsResponse};
+pub use data_transfer_objects::{CreateMatchRequest, MatchResponse};
+pub use data_transfer_objects::EditHandRequest;
+pub use data_transfer_objects::{CreateTournamentRequest, StandingResponse, TournamentResponse};
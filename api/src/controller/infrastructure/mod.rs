@@ -1,11 +1,19 @@
 use axum::http::StatusCode;
-use crate::application::{DeclareTrumpError, GetCompletedHandsError, GetCurrentHandError, GetRunningTotalError, RecordBidError, RecordMeldError, RecordTricksError, StartNewGameError, StartNewHandError};
+use crate::application::{DeclareTrumpError, GetCompletedHandsError, GetCurrentHandError, GetGameError, GetLeaderboardError, GetPlayerStatsError, GetRatingsError, RecomputeRatingsError, CreateMatchError, GetMatchError, CreateTournamentError, GetTournamentError, GetRunningTotalError, GetTimelineError, ListGamesError, RecordAllPassError, UndoActionError, RedoActionError, SettleGameOutcomeError, EditCompletedHandError, PlayCardError, RecordBidError, RecordCallError, RecordWidowError, SubstitutePlayerError, RecordCountersError, RecordMeldError, RecordTricksError, StartNewGameError, StartNewHandError, StartPlayError};
 use crate::controller::error_response::ToResponse;
-use crate::domain::{GameError, GameRepositoryError, HandError, HandRepositoryError};
+use crate::domain::{EventStoreError, GameError, GameRepositoryError, HandError, HandRepositoryError, MatchError, MatchRepositoryError, RatingRepositoryError, TournamentError, TournamentRepositoryError};
 
 impl ToResponse for GameError {
     fn to_response(&self) -> (StatusCode, String, u16) {
-        (StatusCode::INTERNAL_SERVER_ERROR, self.to_string(), 500)
+        match self {
+            GameError::GameOver => (StatusCode::CONFLICT, self.to_string(), 409),
+            GameError::NothingToUndo | GameError::NothingToRedo => (StatusCode::CONFLICT, self.to_string(), 409),
+            GameError::HandInProgress(_) => (StatusCode::CONFLICT, self.to_string(), 409),
+            GameError::HandNotFound(hand_id) => (StatusCode::NOT_FOUND, hand_id.to_string(), 404),
+            GameError::NotReached(_) => (StatusCode::NOT_FOUND, self.to_string(), 404),
+            GameError::InvalidSeating(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
+            _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string(), 500)
+        }
     }
 }
 
@@ -14,6 +22,12 @@ impl ToResponse for HandError {
         match self {
             HandError::InvalidBid(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
             HandError::InvalidTricks(_, _) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
+            HandError::InvalidMeld(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
+            HandError::InvalidCounters(_, _) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
+            HandError::IllegalPlay(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
+            HandError::InvalidWidow(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
+            HandError::UnsupportedTable(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
+            HandError::NotSeated(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
             _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string(), 500)
         }
     }
@@ -28,6 +42,114 @@ impl ToResponse for GameRepositoryError {
     }
 }
 
+impl ToResponse for EventStoreError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            EventStoreError::StreamDoesNotExist(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            EventStoreError::StreamAlreadyExists(game_id) => (StatusCode::CONFLICT, game_id.to_string(), 409),
+            EventStoreError::Conflict { .. } => (StatusCode::CONFLICT, self.to_string(), 409),
+        }
+    }
+}
+
+impl ToResponse for MatchRepositoryError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            MatchRepositoryError::MatchDoesNotExist(match_id) => (StatusCode::NOT_FOUND, match_id.to_string(), 404),
+            MatchRepositoryError::Conflict { .. } => (StatusCode::CONFLICT, self.to_string(), 409),
+        }
+    }
+}
+
+impl ToResponse for MatchError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            MatchError::InvalidWinsRequired(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
+            _ => (StatusCode::CONFLICT, self.to_string(), 409),
+        }
+    }
+}
+
+impl ToResponse for CreateMatchError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            CreateMatchError::StartNewGameError(start_error) => start_error.to_response(),
+            CreateMatchError::InvalidMatch(match_error) => match_error.to_response(),
+            CreateMatchError::EventStoreError(store_error) => store_error.to_response(),
+            CreateMatchError::MatchRepositoryError(repo_error) => repo_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for GetMatchError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            GetMatchError::MatchNotFound(match_id) => (StatusCode::NOT_FOUND, match_id.to_string(), 404),
+            GetMatchError::RepositoryError(repo_error) => repo_error.to_response(),
+            GetMatchError::MatchRepositoryError(repo_error) => repo_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for TournamentRepositoryError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            TournamentRepositoryError::TournamentDoesNotExist(tournament_id) => (StatusCode::NOT_FOUND, tournament_id.to_string(), 404),
+        }
+    }
+}
+
+impl ToResponse for TournamentError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        (StatusCode::BAD_REQUEST, self.to_string(), 400)
+    }
+}
+
+impl ToResponse for CreateTournamentError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            CreateTournamentError::InvalidTournament(tournament_error) => tournament_error.to_response(),
+            CreateTournamentError::EventStoreError(store_error) => store_error.to_response(),
+            CreateTournamentError::TournamentRepositoryError(repo_error) => repo_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for GetTournamentError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            GetTournamentError::TournamentNotFound(tournament_id) => (StatusCode::NOT_FOUND, tournament_id.to_string(), 404),
+            GetTournamentError::RepositoryError(repo_error) => repo_error.to_response(),
+            GetTournamentError::TournamentRepositoryError(repo_error) => repo_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for RatingRepositoryError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            RatingRepositoryError::Conflict { .. } => (StatusCode::CONFLICT, self.to_string(), 409),
+        }
+    }
+}
+
+impl ToResponse for GetRatingsError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            GetRatingsError::RepositoryError(repo_error) => repo_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for RecomputeRatingsError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            RecomputeRatingsError::RepositoryError(repo_error) => repo_error.to_response(),
+            RecomputeRatingsError::RatingRepositoryError(repo_error) => repo_error.to_response(),
+        }
+    }
+}
+
 impl ToResponse for HandRepositoryError {
     fn to_response(&self) -> (StatusCode, String, u16) {
         match self {
@@ -41,6 +163,7 @@ impl ToResponse for DeclareTrumpError {
         match self {
             DeclareTrumpError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
             DeclareTrumpError::RepositoryError(repo_error) => repo_error.to_response(),
+            DeclareTrumpError::EventStoreError(store_error) => store_error.to_response(),
             _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string(), 500)
         }
     }
@@ -49,6 +172,7 @@ impl ToResponse for DeclareTrumpError {
 impl ToResponse for GetCompletedHandsError {
     fn to_response(&self) -> (StatusCode, String, u16) {
         match self {
+            GetCompletedHandsError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
             GetCompletedHandsError::GameRepoError(repo_error) => repo_error.to_response(),
             _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string(), 500)
         }
@@ -65,6 +189,52 @@ impl ToResponse for GetCurrentHandError {
     }
 }
 
+impl ToResponse for GetTimelineError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            GetTimelineError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            GetTimelineError::RepositoryError(repo_error) => repo_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for ListGamesError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            ListGamesError::InvalidPage(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
+            ListGamesError::RepositoryError(repo_error) => repo_error.to_response(),
+            ListGamesError::EventStoreError(store_error) => store_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for GetGameError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            GetGameError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            GetGameError::RepositoryError(repo_error) => repo_error.to_response(),
+            GetGameError::EventStoreError(store_error) => store_error.to_response(),
+            GetGameError::GameError(game_error) => game_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for GetLeaderboardError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            GetLeaderboardError::RepositoryError(repo_error) => repo_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for GetPlayerStatsError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            GetPlayerStatsError::InvalidDateRange(..) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
+            GetPlayerStatsError::RepositoryError(repo_error) => repo_error.to_response(),
+        }
+    }
+}
 
 impl ToResponse for GetRunningTotalError {
     fn to_response(&self) -> (StatusCode, String, u16) {
@@ -81,17 +251,87 @@ impl ToResponse for RecordBidError {
         match self {
             RecordBidError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
             RecordBidError::RepositoryError(repo_error) => repo_error.to_response(),
+            RecordBidError::EventStoreError(store_error) => store_error.to_response(),
             RecordBidError::GameError(game_error) => game_error.to_response(),
             _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string(), 500)
         }
     }
 }
 
+impl ToResponse for RecordCallError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            RecordCallError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            RecordCallError::RepositoryError(repo_error) => repo_error.to_response(),
+            RecordCallError::EventStoreError(store_error) => store_error.to_response(),
+            RecordCallError::GameError(game_error) => game_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for SettleGameOutcomeError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            SettleGameOutcomeError::InvalidMatch(match_error) => match_error.to_response(),
+            SettleGameOutcomeError::MatchRepositoryError(repo_error) => repo_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for UndoActionError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            UndoActionError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            UndoActionError::RepositoryError(repo_error) => repo_error.to_response(),
+            UndoActionError::EventStoreError(store_error) => store_error.to_response(),
+            UndoActionError::GameError(game_error) => game_error.to_response(),
+            UndoActionError::OutcomeError(outcome_error) => outcome_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for RedoActionError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            RedoActionError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            RedoActionError::RepositoryError(repo_error) => repo_error.to_response(),
+            RedoActionError::EventStoreError(store_error) => store_error.to_response(),
+            RedoActionError::GameError(game_error) => game_error.to_response(),
+            RedoActionError::OutcomeError(outcome_error) => outcome_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for EditCompletedHandError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            EditCompletedHandError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            EditCompletedHandError::RepositoryError(repo_error) => repo_error.to_response(),
+            EditCompletedHandError::EventStoreError(store_error) => store_error.to_response(),
+            EditCompletedHandError::GameError(GameError::HandError(hand_error)) => hand_error.to_response(),
+            EditCompletedHandError::GameError(game_error) => game_error.to_response(),
+            EditCompletedHandError::OutcomeError(outcome_error) => outcome_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for RecordAllPassError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            RecordAllPassError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            RecordAllPassError::RepositoryError(repo_error) => repo_error.to_response(),
+            RecordAllPassError::EventStoreError(store_error) => store_error.to_response(),
+            RecordAllPassError::GameError(game_error) => game_error.to_response(),
+        }
+    }
+}
+
 impl ToResponse for RecordMeldError {
     fn to_response(&self) -> (StatusCode, String, u16) {
         match self {
             RecordMeldError::GameNotFound => (StatusCode::NOT_FOUND, self.to_string(), 404),
             RecordMeldError::RepositoryError(repo_error) => repo_error.to_response(),
+            RecordMeldError::EventStoreError(store_error) => store_error.to_response(),
             RecordMeldError::GameError(game_error) => game_error.to_response(),
             _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string(), 500)
         }
@@ -103,6 +343,7 @@ impl ToResponse for RecordTricksError {
         match self {
             RecordTricksError::GameNotFound => (StatusCode::NOT_FOUND, self.to_string(), 404),
             RecordTricksError::RepositoryError(repo_error) => repo_error.to_response(),
+            RecordTricksError::EventStoreError(store_error) => store_error.to_response(),
             RecordTricksError::GameError(game_error) => game_error.to_response(),
             _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string(), 500)
         }
@@ -112,8 +353,10 @@ impl ToResponse for RecordTricksError {
 impl ToResponse for StartNewGameError {
     fn to_response(&self) -> (StatusCode, String, u16) {
         match self {
-            StartNewGameError::GameRepositoryError(repo_error) => repo_error.to_response(),
-            _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string(), 500)
+            StartNewGameError::EventStoreError(store_error) => store_error.to_response(),
+            StartNewGameError::InvalidRuleSet(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
+            StartNewGameError::InvalidTable(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
+            StartNewGameError::InvalidSeating(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
         }
     }
 }
@@ -123,8 +366,64 @@ impl ToResponse for StartNewHandError {
         match self {
             StartNewHandError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
             StartNewHandError::RepositoryError(repo_error) => repo_error.to_response(),
+            StartNewHandError::EventStoreError(store_error) => store_error.to_response(),
             StartNewHandError::GameError(game_error) => game_error.to_response(),
             _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string(), 500)
         }
     }
 }
+
+impl ToResponse for RecordCountersError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            RecordCountersError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            RecordCountersError::RepositoryError(repo_error) => repo_error.to_response(),
+            RecordCountersError::EventStoreError(store_error) => store_error.to_response(),
+            RecordCountersError::GameError(game_error) => game_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for StartPlayError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            StartPlayError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            StartPlayError::RepositoryError(repo_error) => repo_error.to_response(),
+            StartPlayError::EventStoreError(store_error) => store_error.to_response(),
+            StartPlayError::GameError(game_error) => game_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for PlayCardError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            PlayCardError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            PlayCardError::RepositoryError(repo_error) => repo_error.to_response(),
+            PlayCardError::EventStoreError(store_error) => store_error.to_response(),
+            PlayCardError::GameError(game_error) => game_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for RecordWidowError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            RecordWidowError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            RecordWidowError::RepositoryError(repo_error) => repo_error.to_response(),
+            RecordWidowError::EventStoreError(store_error) => store_error.to_response(),
+            RecordWidowError::GameError(game_error) => game_error.to_response(),
+        }
+    }
+}
+
+impl ToResponse for SubstitutePlayerError {
+    fn to_response(&self) -> (StatusCode, String, u16) {
+        match self {
+            SubstitutePlayerError::GameNotFound(game_id) => (StatusCode::NOT_FOUND, game_id.to_string(), 404),
+            SubstitutePlayerError::RepositoryError(repo_error) => repo_error.to_response(),
+            SubstitutePlayerError::EventStoreError(store_error) => store_error.to_response(),
+            SubstitutePlayerError::GameError(game_error) => game_error.to_response(),
+        }
+    }
+}
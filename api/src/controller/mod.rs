@@ -2,13 +2,13 @@ use std::sync::Arc;
 use std::time::Duration;
 use axum::{
     Router,
-    routing::{get, post},
+    routing::{get, post, put},
     http::{
         StatusCode
     },
     response::{IntoResponse, Response},
     Json,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     debug_handler
 };
 use axum::http::{header, HeaderMap, HeaderValue, Method, Request};
@@ -16,15 +16,39 @@ use crate::application::{
     DeclareTrump, DeclareTrumpError, 
     GetCompletedHands, GetCompletedHandsError, 
     GetCurrentHand, GetCurrentHandError, 
+    GetGame, GetGameError,
+    GetLeaderboard, GetLeaderboardError,
+    GetPlayerStats, GetPlayerStatsError,
     GetRunningTotal, GetRunningTotalError, 
+    GetTimeline, GetTimelineError,
+    ListGames, ListGamesError,
+    GetRatings, GetRatingsError,
+    RecomputeRatings, RecomputeRatingsError,
+    UpdateRatings,
+    AdvanceMatch,
+    SettleGameOutcome,
+    CreateMatch, CreateMatchError,
+    GetMatch, GetMatchError,
+    CreateTournament, CreateTournamentError,
+    GetTournament, GetTournamentError,
     RecordBid, RecordBidError, 
+    RecordCall, RecordCallError,
+    RecordAllPass, RecordAllPassError,
     RecordMeld, RecordMeldError, 
     RecordTricks, RecordTricksError, 
+    RecordCounters, RecordCountersError,
+    StartPlay, StartPlayError,
+    PlayCard, PlayCardError,
+    RecordWidow, RecordWidowError,
+    SubstitutePlayer, SubstitutePlayerError,
+    UndoAction, UndoActionError,
+    RedoAction, RedoActionError,
+    EditCompletedHand, EditCompletedHandError,
     StartNewGame, StartNewGameError, 
     StartNewHand, StartNewHandError};
 use crate::AppState;
-use crate::domain::{GameId, GameRepository, GameRepositoryError};
-use crate::infrastructure::InMemoryGameRepository;
+use crate::domain::{EventStore, GameId, GameRepository, HandId, GameRepositoryError, MatchId, MatchRepository, RatingRepository, TournamentId, TournamentRepository};
+use crate::infrastructure::{InMemoryEventStore, InMemoryGameRepository, InMemoryMatchRepository, InMemoryRatingRepository, InMemoryTournamentRepository};
 use serde_json::json;
 use thiserror::Error;
 use tower_http::cors::{Any, CorsLayer};
@@ -41,11 +65,35 @@ use data_transfer::{StartNewGameRequest,
     DeclareTrumpRequest, 
     HandResponse, 
     RecordBidRequest, 
+    RecordCallRequest,
     RecordMeldRequest, 
     RecordTricksRequest, 
+    RecordCountersRequest,
+    StartPlayRequest,
+    PlayCardRequest,
+    RecordWidowRequest,
+    SubstitutePlayerRequest,
+    EditHandRequest,
     RunningTotalResponse, 
     StartNewHandRequest,
     GameResponse,
+    GameAsOfQuery,
+    GameDetailResponse,
+    GameListResponse,
+    ListGamesQuery,
+    GameResponseV2,
+    RunningTotalResponseV2,
+    TimelineEntryResponse,
+    PlayerStatsQuery,
+    PlayerStatsResponse,
+    LeaderboardQuery,
+    LeaderboardResponse,
+    RatingsResponse,
+    CreateMatchRequest,
+    MatchResponse,
+    CreateTournamentRequest,
+    StandingResponse,
+    TournamentResponse,
 };
 use crate::controller::environment::Environment;
 use crate::controller::error_response::ToResponse;
@@ -60,7 +108,7 @@ pub async fn start_new_game_handler(State(state): State<AppState>, headers: Head
 
     let AppState { start_game, .. } = state;
 
-    let game = start_game.execute(payload.dealer).await?;
+    let game = start_game.execute(payload.dealer, payload.rule_set(), payload.seating()).await?;
 
     tracing::info!("game: {:#?}", game);
 
@@ -75,7 +123,7 @@ pub async fn start_new_hand_handler(State(state): State<AppState>, Json(payload)
 
     let AppState { start_hand, .. } = state;
 
-    let game = start_hand.execute(GameId(payload.game_id)).await?;
+    let game = start_hand.execute(GameId(payload.game_id), payload.seed).await?;
     tracing::info!("start_new_hand_handler: got game");
 
     let dto = GameResponse::from(&game);
@@ -120,6 +168,169 @@ pub async fn get_running_total_handler(State(state): State<AppState>, Path(game_
     Ok(Json(dto))
 }
 
+pub async fn get_timeline_handler(State(state): State<AppState>, Path(game_id): Path<String>) -> Result<Json<Vec<TimelineEntryResponse>>, AppError> {
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+    tracing::info!("get_timeline_handler");
+    let AppState { get_timeline, .. } = state;
+
+    let timeline = get_timeline.execute(GameId(id)).await?;
+    let dto = timeline.iter().map(TimelineEntryResponse::from).collect();
+
+    Ok(Json(dto))
+}
+
+pub async fn get_game_v2_handler(State(state): State<AppState>, Path(game_id): Path<String>) -> Result<Json<GameResponseV2>, AppError> {
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+    tracing::info!("get_game_v2_handler");
+    let AppState { get_game, .. } = state;
+
+    let game = get_game.execute(GameId(id)).await?;
+    let dto = GameResponseV2::from(&game);
+
+    Ok(Json(dto))
+}
+
+pub async fn get_game_handler(State(state): State<AppState>, Path(game_id): Path<String>, Query(query): Query<GameAsOfQuery>) -> Result<Json<GameDetailResponse>, AppError> {
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+    tracing::info!("get_game_handler");
+    let AppState { get_game, .. } = state;
+
+    let game = match (query.as_of_hand, query.after) {
+        (Some(hand_number), after) => get_game.as_of(GameId(id), hand_number, after).await?,
+        (None, Some(_)) => return Err(AppError::InvalidRequest("after needs as_of_hand".to_string())),
+        (None, None) => get_game.execute(GameId(id)).await?,
+    };
+    let dto = GameDetailResponse::from(&game);
+
+    Ok(Json(dto))
+}
+
+pub async fn list_games_handler(State(state): State<AppState>, Query(query): Query<ListGamesQuery>) -> Result<Json<GameListResponse>, AppError> {
+    tracing::info!("list_games_handler");
+    let AppState { list_games, .. } = state;
+
+    let page = list_games.execute(query.state, query.sort, query.order, query.page, query.per_page).await?;
+    let dto = GameListResponse::from(&page);
+
+    Ok(Json(dto))
+}
+
+pub async fn get_running_total_v2_handler(State(state): State<AppState>, Path(game_id): Path<String>) -> Result<Json<RunningTotalResponseV2>, AppError> {
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+    tracing::info!("get_running_total_v2_handler");
+    let AppState { get_running_total, .. } = state;
+
+    let total = get_running_total.execute(GameId(id)).await?;
+    let dto = RunningTotalResponseV2::from(&total);
+
+    Ok(Json(dto))
+}
+
+pub async fn get_player_stats_handler(State(state): State<AppState>, Query(query): Query<PlayerStatsQuery>) -> Result<Json<PlayerStatsResponse>, AppError> {
+    tracing::info!("get_player_stats_handler");
+    let AppState { get_player_stats, .. } = state;
+
+    let report = get_player_stats.execute(query.from, query.to).await?;
+    let dto = PlayerStatsResponse::from(&report);
+
+    Ok(Json(dto))
+}
+
+pub async fn get_leaderboard_handler(State(state): State<AppState>, Query(query): Query<LeaderboardQuery>) -> Result<Json<LeaderboardResponse>, AppError> {
+    tracing::info!("get_leaderboard_handler");
+    let AppState { get_leaderboard, .. } = state;
+
+    let leaderboard = get_leaderboard.execute(query.by, query.sort).await?;
+    let dto = LeaderboardResponse::new(&query, &leaderboard);
+
+    Ok(Json(dto))
+}
+
+pub async fn get_ratings_handler(State(state): State<AppState>) -> Result<Json<RatingsResponse>, AppError> {
+    tracing::info!("get_ratings_handler");
+    let AppState { get_ratings, .. } = state;
+
+    let ratings = get_ratings.execute().await?;
+    let dto = RatingsResponse::from(&ratings);
+
+    Ok(Json(dto))
+}
+
+pub async fn recompute_ratings_handler(State(state): State<AppState>) -> Result<Json<RatingsResponse>, AppError> {
+    tracing::info!("recompute_ratings_handler");
+    let AppState { recompute_ratings, .. } = state;
+
+    let ratings = recompute_ratings.execute().await?;
+    let dto = RatingsResponse::from(&ratings);
+
+    Ok(Json(dto))
+}
+
+pub async fn create_match_handler(State(state): State<AppState>, Json(payload): Json<CreateMatchRequest>) -> Result<Json<MatchResponse>, AppError> {
+    tracing::info!("create_match_handler");
+    let AppState { create_match, .. } = state;
+    let CreateMatchRequest { game, wins_required } = payload;
+
+    let game_match = create_match.execute(game.dealer, game.rule_set(), game.seating(), wins_required).await?;
+    let dto = MatchResponse::from(&game_match);
+
+    Ok(Json(dto))
+}
+
+pub async fn get_match_handler(State(state): State<AppState>, Path(match_id): Path<String>) -> Result<Json<MatchResponse>, AppError> {
+    let id = Uuid::parse_str(&match_id).map_err(|_| AppError::GetParseUuidError(match_id.clone()))?;
+    tracing::info!("get_match_handler");
+    let AppState { get_match, .. } = state;
+
+    let game_match = get_match.execute(MatchId(id)).await?;
+    let dto = MatchResponse::from(&game_match);
+
+    Ok(Json(dto))
+}
+
+pub async fn get_match_games_handler(State(state): State<AppState>, Path(match_id): Path<String>) -> Result<Json<Vec<GameResponseV2>>, AppError> {
+    let id = Uuid::parse_str(&match_id).map_err(|_| AppError::GetParseUuidError(match_id.clone()))?;
+    tracing::info!("get_match_games_handler");
+    let AppState { get_match, .. } = state;
+
+    let games = get_match.games(MatchId(id)).await?;
+    let dto = games.iter().map(GameResponseV2::from).collect();
+
+    Ok(Json(dto))
+}
+
+pub async fn create_tournament_handler(State(state): State<AppState>, Json(payload): Json<CreateTournamentRequest>) -> Result<Json<TournamentResponse>, AppError> {
+    tracing::info!("create_tournament_handler");
+    let AppState { create_tournament, .. } = state;
+
+    let tournament = create_tournament.execute(payload.name.clone(), payload.entrants(), payload.rule_set(), payload.rounds).await?;
+    let dto = TournamentResponse::from(&tournament);
+
+    Ok(Json(dto))
+}
+
+pub async fn get_tournament_handler(State(state): State<AppState>, Path(tournament_id): Path<String>) -> Result<Json<TournamentResponse>, AppError> {
+    let id = Uuid::parse_str(&tournament_id).map_err(|_| AppError::GetParseUuidError(tournament_id.clone()))?;
+    tracing::info!("get_tournament_handler");
+    let AppState { get_tournament, .. } = state;
+
+    let tournament = get_tournament.execute(TournamentId(id)).await?;
+    let dto = TournamentResponse::from(&tournament);
+
+    Ok(Json(dto))
+}
+
+pub async fn get_tournament_standings_handler(State(state): State<AppState>, Path(tournament_id): Path<String>) -> Result<Json<Vec<StandingResponse>>, AppError> {
+    let id = Uuid::parse_str(&tournament_id).map_err(|_| AppError::GetParseUuidError(tournament_id.clone()))?;
+    tracing::info!("get_tournament_standings_handler");
+    let AppState { get_tournament, .. } = state;
+
+    let standings = get_tournament.standings(TournamentId(id)).await?;
+    let dto = standings.iter().enumerate().map(|(index, standing)| StandingResponse::new(index + 1, standing)).collect();
+
+    Ok(Json(dto))
+}
+
 pub async fn record_bid_handler(State(state): State<AppState>, Path(game_id): Path<String>, Json(payload): Json<RecordBidRequest>) -> Result<Json<GameResponse>, AppError> {
     tracing::info!("record_bid_handler");
     let AppState { record_bid, .. } = state;
@@ -131,6 +342,65 @@ pub async fn record_bid_handler(State(state): State<AppState>, Path(game_id): Pa
     Ok(Json(dto))
 }
 
+pub async fn record_call_handler(State(state): State<AppState>, Path(game_id): Path<String>, Json(payload): Json<RecordCallRequest>) -> Result<Json<GameResponse>, AppError> {
+    tracing::info!("record_call_handler");
+    let AppState { record_call, .. } = state;
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+
+    let call = payload.call()
+        .ok_or_else(|| AppError::InvalidRequest("Record either a bid or a pass".to_string()))?;
+
+    let game = record_call.execute(GameId(id), payload.player, call).await?;
+    let dto = GameResponse::from(&game);
+
+    Ok(Json(dto))
+}
+
+pub async fn record_all_pass_handler(State(state): State<AppState>, Path(game_id): Path<String>) -> Result<Json<GameResponse>, AppError> {
+    tracing::info!("record_all_pass_handler");
+    let AppState { record_all_pass, .. } = state;
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+
+    let game = record_all_pass.execute(GameId(id)).await?;
+    let dto = GameResponse::from(&game);
+
+    Ok(Json(dto))
+}
+
+pub async fn undo_action_handler(State(state): State<AppState>, Path(game_id): Path<String>) -> Result<Json<GameResponse>, AppError> {
+    tracing::info!("undo_action_handler");
+    let AppState { undo_action, .. } = state;
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+
+    let game = undo_action.execute(GameId(id)).await?;
+    let dto = GameResponse::from(&game);
+
+    Ok(Json(dto))
+}
+
+pub async fn redo_action_handler(State(state): State<AppState>, Path(game_id): Path<String>) -> Result<Json<GameResponse>, AppError> {
+    tracing::info!("redo_action_handler");
+    let AppState { redo_action, .. } = state;
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+
+    let game = redo_action.execute(GameId(id)).await?;
+    let dto = GameResponse::from(&game);
+
+    Ok(Json(dto))
+}
+
+pub async fn edit_completed_hand_handler(State(state): State<AppState>, Path((game_id, hand_id)): Path<(String, String)>, Json(payload): Json<EditHandRequest>) -> Result<Json<GameResponseV2>, AppError> {
+    tracing::info!("edit_completed_hand_handler");
+    let AppState { edit_completed_hand, .. } = state;
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+    let hand = Uuid::parse_str(&hand_id).map_err(|_| AppError::GetParseUuidError(hand_id.clone()))?;
+
+    let game = edit_completed_hand.execute(GameId(id), HandId(hand), payload.correction()).await?;
+    let dto = GameResponseV2::from(&game);
+
+    Ok(Json(dto))
+}
+
 pub async fn declare_trump_handler(State(state): State<AppState>, Path(game_id): Path<String>, Json(payload): Json<DeclareTrumpRequest>) -> Result<Json<GameResponse>, AppError> {
     let AppState { declare_trump, .. } = state;
     let id = Uuid::parse_str(&game_id).map_err(|e| AppError::GetParseUuidError(game_id.clone()))?;
@@ -148,7 +418,10 @@ pub async fn record_meld_handler(State(state): State<AppState>, Path(game_id): P
     let AppState { record_meld, .. } = state;
     let id = Uuid::parse_str(&game_id).map_err(|e| AppError::GetParseUuidError(game_id.clone()))?;
 
-    let game = record_meld.execute(GameId(id), payload.us_meld, payload.them_meld).await?;
+    let entry = payload.entry()
+        .ok_or_else(|| AppError::InvalidRequest("Record either us_meld and them_meld, by_player or meld_cards".to_string()))?;
+
+    let game = record_meld.execute(GameId(id), entry).await?;
 
     let dto = GameResponse::from(&game);
 
@@ -159,8 +432,66 @@ pub async fn record_tricks_handler(State(state): State<AppState>, Path(game_id):
     let AppState { record_tricks, .. } = state;
     let id = Uuid::parse_str(&game_id).map_err(|e| AppError::GetParseUuidError(game_id.clone()))?;
 
-    let game = record_tricks.execute(GameId(id), payload.us_tricks, payload.them_tricks).await?;
+    let entry = payload.entry()
+        .ok_or_else(|| AppError::InvalidRequest("Record either us_tricks and them_tricks or by_player".to_string()))?;
+
+    let game = record_tricks.execute(GameId(id), entry).await?;
+
+    let dto = GameResponse::from(&game);
+
+    Ok(Json(dto))
+}
+
+pub async fn record_counters_handler(State(state): State<AppState>, Path(game_id): Path<String>, Json(payload): Json<RecordCountersRequest>) -> Result<Json<GameResponse>, AppError> {
+    tracing::info!("record_counters_handler");
+    let AppState { record_counters, .. } = state;
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+
+    let game = record_counters.execute(GameId(id), payload.us_counters, payload.them_counters, payload.last_trick).await?;
+    let dto = GameResponse::from(&game);
 
+    Ok(Json(dto))
+}
+
+pub async fn start_play_handler(State(state): State<AppState>, Path(game_id): Path<String>, Json(payload): Json<StartPlayRequest>) -> Result<Json<GameResponse>, AppError> {
+    tracing::info!("start_play_handler");
+    let AppState { start_play, .. } = state;
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+
+    let game = start_play.execute(GameId(id), payload.hands).await?;
+    let dto = GameResponse::from(&game);
+
+    Ok(Json(dto))
+}
+
+pub async fn play_card_handler(State(state): State<AppState>, Path(game_id): Path<String>, Json(payload): Json<PlayCardRequest>) -> Result<Json<GameResponse>, AppError> {
+    tracing::info!("play_card_handler");
+    let AppState { play_card, .. } = state;
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+
+    let game = play_card.execute(GameId(id), payload.player, payload.card).await?;
+    let dto = GameResponse::from(&game);
+
+    Ok(Json(dto))
+}
+
+pub async fn record_widow_handler(State(state): State<AppState>, Path(game_id): Path<String>, Json(payload): Json<RecordWidowRequest>) -> Result<Json<GameResponse>, AppError> {
+    tracing::info!("record_widow_handler");
+    let AppState { record_widow, .. } = state;
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+
+    let game = record_widow.execute(GameId(id), payload.widow).await?;
+    let dto = GameResponse::from(&game);
+
+    Ok(Json(dto))
+}
+
+pub async fn substitute_player_handler(State(state): State<AppState>, Path(game_id): Path<String>, Json(payload): Json<SubstitutePlayerRequest>) -> Result<Json<GameResponse>, AppError> {
+    tracing::info!("substitute_player_handler");
+    let AppState { substitute_player, .. } = state;
+    let id = Uuid::parse_str(&game_id).map_err(|_| AppError::GetParseUuidError(game_id.clone()))?;
+
+    let game = substitute_player.execute(GameId(id), payload.player.seat, payload.player.profile(), payload.from_hand).await?;
     let dto = GameResponse::from(&game);
 
     Ok(Json(dto))
@@ -168,16 +499,44 @@ pub async fn record_tricks_handler(State(state): State<AppState>, Path(game_id):
 
 // --- Router setup ---
 pub fn router(env: &Environment) -> Router {
-    let repo: Arc<dyn GameRepository> = Arc::new(InMemoryGameRepository::new());
-    let start_game = Arc::new(StartNewGame::new(repo.clone()));
-    let start_hand = Arc::new(StartNewHand::new(repo.clone()));
-    let record_bid = Arc::new(RecordBid::new(repo.clone()));
-    let declare_trump = Arc::new(DeclareTrump::new(repo.clone()));
-    let record_meld = Arc::new(RecordMeld::new(repo.clone()));
-    let record_tricks = Arc::new(RecordTricks::new(repo.clone()));
+    let event_store: Arc<dyn EventStore> = Arc::new(InMemoryEventStore::new());
+    let repo: Arc<dyn GameRepository> = Arc::new(InMemoryGameRepository::new(event_store.clone()));
+    let rating_repo: Arc<dyn RatingRepository> = Arc::new(InMemoryRatingRepository::new());
+    let match_repo: Arc<dyn MatchRepository> = Arc::new(InMemoryMatchRepository::new());
+    let tournament_repo: Arc<dyn TournamentRepository> = Arc::new(InMemoryTournamentRepository::new());
+    let update_ratings = Arc::new(UpdateRatings::new(rating_repo.clone()));
+    let advance_match = Arc::new(AdvanceMatch::new(event_store.clone(), match_repo.clone()));
+    let recompute_ratings = Arc::new(RecomputeRatings::new(repo.clone(), rating_repo.clone()));
+    let settle_outcome = Arc::new(SettleGameOutcome::new(update_ratings.clone(), recompute_ratings.clone(), advance_match.clone()));
+    let start_game = Arc::new(StartNewGame::new(event_store.clone()));
+    let start_hand = Arc::new(StartNewHand::new(repo.clone(), event_store.clone()));
+    let record_bid = Arc::new(RecordBid::new(repo.clone(), event_store.clone()));
+    let record_call = Arc::new(RecordCall::new(repo.clone(), event_store.clone()));
+    let record_all_pass = Arc::new(RecordAllPass::new(repo.clone(), event_store.clone()));
+    let declare_trump = Arc::new(DeclareTrump::new(repo.clone(), event_store.clone()));
+    let record_meld = Arc::new(RecordMeld::new(repo.clone(), event_store.clone()).with_outcome(settle_outcome.clone()));
+    let record_tricks = Arc::new(RecordTricks::new(repo.clone(), event_store.clone()).with_outcome(settle_outcome.clone()));
+    let record_counters = Arc::new(RecordCounters::new(repo.clone(), event_store.clone()).with_outcome(settle_outcome.clone()));
+    let start_play = Arc::new(StartPlay::new(repo.clone(), event_store.clone()));
+    let play_card = Arc::new(PlayCard::new(repo.clone(), event_store.clone()).with_outcome(settle_outcome.clone()));
+    let record_widow = Arc::new(RecordWidow::new(repo.clone(), event_store.clone()));
+    let substitute_player = Arc::new(SubstitutePlayer::new(repo.clone(), event_store.clone()));
+    let undo_action = Arc::new(UndoAction::new(repo.clone(), event_store.clone()).with_outcome(settle_outcome.clone()));
+    let redo_action = Arc::new(RedoAction::new(repo.clone(), event_store.clone()).with_outcome(settle_outcome.clone()));
+    let edit_completed_hand = Arc::new(EditCompletedHand::new(repo.clone(), event_store.clone()).with_outcome(settle_outcome.clone()));
     let get_completed_hands = Arc::new(GetCompletedHands::new(repo.clone()));
     let get_current_hand = Arc::new(GetCurrentHand::new(repo.clone()));
     let get_running_total = Arc::new(GetRunningTotal::new(repo.clone()));
+    let get_timeline = Arc::new(GetTimeline::new(repo.clone()));
+    let get_game = Arc::new(GetGame::new(repo.clone(), event_store.clone()));
+    let list_games = Arc::new(ListGames::new(repo.clone(), event_store.clone()));
+    let get_player_stats = Arc::new(GetPlayerStats::new(repo.clone()));
+    let get_leaderboard = Arc::new(GetLeaderboard::new(repo.clone()));
+    let get_ratings = Arc::new(GetRatings::new(rating_repo.clone()));
+    let create_match = Arc::new(CreateMatch::new(event_store.clone(), match_repo.clone()));
+    let get_match = Arc::new(GetMatch::new(repo.clone(), match_repo.clone()));
+    let create_tournament = Arc::new(CreateTournament::new(event_store.clone(), tournament_repo.clone()));
+    let get_tournament = Arc::new(GetTournament::new(repo.clone(), tournament_repo.clone()));
 
     tracing::info!("Stating server in {:?} mode", env);
 
@@ -185,31 +544,81 @@ pub fn router(env: &Environment) -> Router {
         start_game,
         start_hand,
         record_bid,
+        record_call,
+        record_all_pass,
         declare_trump,
         record_meld,
         record_tricks,
+        record_counters,
+        start_play,
+        play_card,
+        record_widow,
+        substitute_player,
+        undo_action,
+        redo_action,
+        edit_completed_hand,
         get_completed_hands,
         get_current_hand,
         get_running_total,
+        get_timeline,
+        get_game,
+        list_games,
+        get_player_stats,
+        get_leaderboard,
+        get_ratings,
+        recompute_ratings,
+        create_match,
+        get_match,
+        create_tournament,
+        get_tournament,
     };
 
     let inner_router = Router::new()
-        .route("/", get(get_completed_hands_handler))
+        .route("/", get(get_game_handler))
         .route("/current_hand", get(get_current_hand_handler))
         .route("/running_total", get(get_running_total_handler))
+        .route("/timeline", get(get_timeline_handler))
         .route("/completed_hands", get(get_completed_hands_handler))
         .route("/record_bid", post(record_bid_handler))
+        .route("/record_call", post(record_call_handler))
+        .route("/all_pass", post(record_all_pass_handler))
+        .route("/record_widow", post(record_widow_handler))
         .route("/declare_trump", post(declare_trump_handler))
         .route("/record_meld", post(record_meld_handler))
         .route("/record_tricks", post(record_tricks_handler))
+        .route("/record_counters", post(record_counters_handler))
+        .route("/start_play", post(start_play_handler))
+        .route("/play_card", post(play_card_handler))
+        .route("/substitute", post(substitute_player_handler))
+        .route("/undo", post(undo_action_handler))
+        .route("/redo", post(redo_action_handler))
+        .route("/hands/{hand_id}", put(edit_completed_hand_handler))
+        .with_state(state.clone());
+
+    let v2_router = Router::new()
+        .route("/", get(get_game_v2_handler))
+        .route("/running_total", get(get_running_total_v2_handler))
         .with_state(state.clone());
 
 
     let mut router = Router::new()
         .route("/api/health", get(health_handler))
-        .route("/api/games/", post(start_new_game_handler))
+        .route("/api/games", get(list_games_handler))
+        .route("/api/games/", post(start_new_game_handler).get(list_games_handler))
         .route("/api/games/start_hand", post(start_new_hand_handler))
+        .route("/api/games/{game_id}", get(get_game_handler))
+        .route("/api/stats/players", get(get_player_stats_handler))
+        .route("/api/leaderboard", get(get_leaderboard_handler))
+        .route("/api/ratings", get(get_ratings_handler))
+        .route("/api/ratings/recompute", post(recompute_ratings_handler))
+        .route("/api/matches/", post(create_match_handler))
+        .route("/api/matches/{match_id}/", get(get_match_handler))
+        .route("/api/matches/{match_id}/games", get(get_match_games_handler))
+        .route("/api/tournaments/", post(create_tournament_handler))
+        .route("/api/tournaments/{tournament_id}/", get(get_tournament_handler))
+        .route("/api/tournaments/{tournament_id}/standings", get(get_tournament_standings_handler))
         .nest("/api/games/{game_id}/", inner_router)
+        .nest("/api/v2/games/{game_id}/", v2_router)
         .with_state(state)
         .layer(TraceLayer::new_for_http()
             .make_span_with(|request: &Request<_>| {
@@ -247,19 +656,63 @@ pub enum AppError {
     #[error(transparent)]
     RecordBidError(#[from] RecordBidError),
     #[error(transparent)]
+    RecordCallError(#[from] RecordCallError),
+    #[error(transparent)]
+    RecordAllPassError(#[from] RecordAllPassError),
+    #[error(transparent)]
+    UndoActionError(#[from] UndoActionError),
+    #[error(transparent)]
+    RedoActionError(#[from] RedoActionError),
+    #[error(transparent)]
+    EditCompletedHandError(#[from] EditCompletedHandError),
+    #[error(transparent)]
     DeclareTrumpError(#[from] DeclareTrumpError),
     #[error(transparent)]
     RecordMeldError(#[from] RecordMeldError),
     #[error(transparent)]
     RecordTricksError(#[from] RecordTricksError),
     #[error(transparent)]
+    RecordCountersError(#[from] RecordCountersError),
+    #[error(transparent)]
+    StartPlayError(#[from] StartPlayError),
+    #[error(transparent)]
+    PlayCardError(#[from] PlayCardError),
+    #[error(transparent)]
+    RecordWidowError(#[from] RecordWidowError),
+    #[error(transparent)]
+    SubstitutePlayerError(#[from] SubstitutePlayerError),
+    #[error(transparent)]
     GetCompletedHandsError(#[from] GetCompletedHandsError),
     #[error(transparent)]
     GetCurrentHandError(#[from] GetCurrentHandError),
     #[error(transparent)]
+    GetGameError(#[from] GetGameError),
+    #[error(transparent)]
+    GetPlayerStatsError(#[from] GetPlayerStatsError),
+    #[error(transparent)]
+    GetLeaderboardError(#[from] GetLeaderboardError),
+    #[error(transparent)]
+    GetRatingsError(#[from] GetRatingsError),
+    #[error(transparent)]
+    RecomputeRatingsError(#[from] RecomputeRatingsError),
+    #[error(transparent)]
+    CreateMatchError(#[from] CreateMatchError),
+    #[error(transparent)]
+    GetMatchError(#[from] GetMatchError),
+    #[error(transparent)]
+    CreateTournamentError(#[from] CreateTournamentError),
+    #[error(transparent)]
+    GetTournamentError(#[from] GetTournamentError),
+    #[error(transparent)]
     GetRunningTotalError(#[from] GetRunningTotalError),
+    #[error(transparent)]
+    GetTimelineError(#[from] GetTimelineError),
+    #[error(transparent)]
+    ListGamesError(#[from] ListGamesError),
     #[error("Parse error: {0}")]
     GetParseUuidError(String),
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
 }
 
 
@@ -269,12 +722,34 @@ impl IntoResponse for AppError {
             AppError::StartNewGameError(e) => e.to_response(),
             AppError::StartNewHandError(e) => e.to_response(),
             AppError::RecordBidError(e) => e.to_response(),
+            AppError::RecordCallError(e) => e.to_response(),
+            AppError::RecordAllPassError(e) => e.to_response(),
+            AppError::UndoActionError(e) => e.to_response(),
+            AppError::RedoActionError(e) => e.to_response(),
+            AppError::EditCompletedHandError(e) => e.to_response(),
             AppError::DeclareTrumpError(e) => e.to_response(),
             AppError::RecordMeldError(e) => e.to_response(),
             AppError::RecordTricksError(e) => e.to_response(),
+            AppError::RecordCountersError(e) => e.to_response(),
+            AppError::StartPlayError(e) => e.to_response(),
+            AppError::PlayCardError(e) => e.to_response(),
+            AppError::RecordWidowError(e) => e.to_response(),
+            AppError::SubstitutePlayerError(e) => e.to_response(),
             AppError::GetCompletedHandsError(e) => e.to_response(),
             AppError::GetCurrentHandError(e) => e.to_response(),
+            AppError::GetGameError(e) => e.to_response(),
+            AppError::GetPlayerStatsError(e) => e.to_response(),
+            AppError::GetLeaderboardError(e) => e.to_response(),
+            AppError::GetRatingsError(e) => e.to_response(),
+            AppError::RecomputeRatingsError(e) => e.to_response(),
+            AppError::CreateMatchError(e) => e.to_response(),
+            AppError::GetMatchError(e) => e.to_response(),
+            AppError::CreateTournamentError(e) => e.to_response(),
+            AppError::GetTournamentError(e) => e.to_response(),
             AppError::GetRunningTotalError(e) => e.to_response(),
+            AppError::GetTimelineError(e) => e.to_response(),
+            AppError::ListGamesError(e) => e.to_response(),
+            AppError::InvalidRequest(_) => (StatusCode::BAD_REQUEST, self.to_string(), 400),
             _ => (StatusCode::INTERNAL_SERVER_ERROR, self.to_string(), 500),
         };
 
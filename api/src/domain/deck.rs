@@ -0,0 +1,148 @@
+use std::collections::HashMap;
+use crate::domain::{Card, DeckKind, Player, Rank, Suit, Table};
+
+/// A full pinochle deck: two copies of each card from nine to ace for a
+/// single deck, four copies of each card from jack to ace for a double deck
+/// and two more copies for every further deck.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deck {
+    cards: Vec<Card>,
+}
+
+impl Deck {
+    pub fn new(deck_kind: DeckKind) -> Self {
+        let ranks: &[Rank] = match deck_kind.has_nines() {
+            true => &[Rank::Nine, Rank::Jack, Rank::Queen, Rank::King, Rank::Ten, Rank::Ace],
+            false => &[Rank::Jack, Rank::Queen, Rank::King, Rank::Ten, Rank::Ace],
+        };
+        let copies = deck_kind.copies() as usize;
+
+        let cards = Suit::PLAYABLE
+            .iter()
+            .flat_map(|suit| ranks.iter().map(move |rank| Card::new(*rank, *suit)))
+            .flat_map(|card| std::iter::repeat_n(card, copies))
+            .collect();
+
+        Self { cards }
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Fisher-Yates shuffle driven by `seed`, so the same seed always gives
+    /// the same order.
+    pub fn shuffled(&self, seed: u64) -> Self {
+        let mut rng = SplitMix64(seed);
+        let mut cards = self.cards.clone();
+        for i in (1..cards.len()).rev() {
+            let j = (rng.next() % (i as u64 + 1)) as usize;
+            cards.swap(i, j);
+        }
+        Self { cards }
+    }
+}
+
+/// The cards each seat was dealt, and the widow set aside for the bid winner.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Deal {
+    pub hands: HashMap<Player, Vec<Card>>,
+    pub widow: Vec<Card>,
+}
+
+/// Deals a seeded shuffle around the table one card at a time, starting
+/// left of the dealer, after setting aside the widow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dealer {
+    seed: u64,
+}
+
+impl Dealer {
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+
+    /// Deals the shuffle, or `None` when the dealer is not seated at the table.
+    pub fn deal(&self, deck_kind: DeckKind, table: &Table, dealer: Player) -> Option<Deal> {
+        let deck = Deck::new(deck_kind).shuffled(self.seed);
+        let (widow, cards) = deck.cards().split_at(table.widow().min(deck.cards().len()));
+
+        let mut hands: HashMap<Player, Vec<Card>> = HashMap::new();
+        let mut seat = table.next_clockwise(dealer)?;
+        for card in cards {
+            hands.entry(seat).or_default().push(*card);
+            seat = table.next_clockwise(seat)?;
+        }
+
+        Some(Deal { hands, widow: widow.to_vec() })
+    }
+}
+
+/// Small, fast generator with a fixed algorithm, so seeds stay reproducible
+/// across platforms and releases.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_build_decks_of_the_right_size() {
+        assert_eq!(Deck::new(DeckKind::SingleDeck).cards().len(), 48);
+        assert_eq!(Deck::new(DeckKind::DoubleDeck).cards().len(), 80);
+        assert_eq!(Deck::new(DeckKind::TripleDeck).cards().len(), 120);
+        assert_eq!(Deck::new(DeckKind::QuadrupleDeck).cards().len(), 160);
+        assert!(Deck::new(DeckKind::DoubleDeck).cards().iter().all(|card| card.rank != Rank::Nine));
+    }
+
+    #[test]
+    fn should_shuffle_the_same_way_for_the_same_seed() {
+        let deck = Deck::new(DeckKind::DoubleDeck);
+
+        assert_eq!(deck.shuffled(42), deck.shuffled(42));
+        assert_ne!(deck.shuffled(42), deck.shuffled(43));
+        assert_ne!(deck.shuffled(42), deck);
+    }
+
+    #[test]
+    fn should_deal_evenly_starting_left_of_dealer() {
+        let deal = Dealer::new(7).deal(DeckKind::SingleDeck, &Table::default(), Player::South).unwrap();
+        let first = Deck::new(DeckKind::SingleDeck).shuffled(7).cards()[0];
+
+        assert!(deal.hands.values().all(|cards| cards.len() == 12));
+        assert!(deal.widow.is_empty());
+        assert_eq!(Dealer::new(7).deal(DeckKind::SingleDeck, &Table::default(), Player::NorthEast), None);
+        assert_eq!(deal.hands[&Player::West][0], first);
+        assert_eq!(deal, Dealer::new(7).deal(DeckKind::SingleDeck, &Table::default(), Player::South).unwrap());
+    }
+
+    #[test]
+    fn should_set_aside_widow_for_cutthroat() {
+        let deal = Dealer::new(7).deal(DeckKind::SingleDeck, &Table::three_handed_cutthroat(3), Player::South).unwrap();
+
+        assert_eq!(deal.widow.len(), 3);
+        assert_eq!(deal.hands.len(), 3);
+        assert!(deal.hands.values().all(|cards| cards.len() == 15));
+    }
+
+    #[test]
+    fn should_deal_twenty_cards_each_at_larger_tables() {
+        let six = Dealer::new(7).deal(DeckKind::TripleDeck, &Table::six_handed_partnership(3), Player::North).unwrap();
+        let eight = Dealer::new(7).deal(DeckKind::QuadrupleDeck, &Table::eight_handed_partnership(), Player::North).unwrap();
+
+        assert_eq!(six.hands.len(), 6);
+        assert!(six.hands.values().all(|cards| cards.len() == 20));
+        assert_eq!(eight.hands.len(), 8);
+        assert!(eight.hands.values().all(|cards| cards.len() == 20));
+    }
+}
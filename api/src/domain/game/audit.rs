@@ -0,0 +1,15 @@
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use crate::domain::{HandId, HandRecord};
+
+/// A correction made to a completed hand, with the values it was scored
+/// with before and after.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HandEdit {
+    pub hand_id: HandId,
+    /// The hand's number in the game, counting from one.
+    pub hand_number: usize,
+    pub before: HandRecord,
+    pub after: HandRecord,
+    pub edited_at: DateTime<Utc>,
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use crate::domain::{Call, Card, Game, GameAction, GameId, HandCorrection, HandId, MeldItem, Player, PlayerProfile, Roster, RuleSet, Suit, Team, Winner};
+
+/// The ways a table can enter meld: team totals, team totals with the named
+/// melds behind them, a value per seat, or the cards each player laid down
+/// for the meld calculator to score.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MeldEntry {
+    Totals { us: u32, them: u32 },
+    ByPlayer(HashMap<Player, u32>),
+    Itemized { us: u32, them: u32, items: HashMap<Player, Vec<MeldItem>> },
+    Cards(HashMap<Player, Vec<Card>>),
+}
+
+/// Trick points can be entered per team or per seat, or as the counters
+/// each team took together with the team that took the last trick.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TricksEntry {
+    Totals { us: u32, them: u32 },
+    ByPlayer(HashMap<Player, u32>),
+    Counters { us: u32, them: u32, last_trick: Team },
+}
+
+/// Something that happened to a game. Folding a game's events in order
+/// rebuilds it exactly; ids and times are carried so a replay does not draw
+/// new ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GameEvent {
+    GameStarted { game_id: GameId, dealer: Player, rule_set: RuleSet, roster: Roster, created_at: DateTime<Utc> },
+    HandStarted { hand_id: HandId, seed: Option<u64> },
+    /// The winning bid, entered without the auction behind it.
+    BidRecorded { bidder: Player, amount: u32 },
+    /// A bid or pass in the auction.
+    CallRecorded { player: Player, call: Call },
+    AllPassed,
+    WidowRecorded { widow: Vec<Card> },
+    TrumpDeclared { trump: Suit },
+    MeldRecorded { meld: MeldEntry },
+    TricksRecorded { tricks: TricksEntry },
+    PlayStarted { hands: Option<HashMap<Player, Vec<Card>>> },
+    CardPlayed { player: Player, card: Card },
+    PlayerSubstituted { seat: Player, player: PlayerProfile, from_hand: Option<usize> },
+    HandCorrected { hand_id: HandId, correction: HandCorrection, edited_at: DateTime<Utc> },
+    ActionUndone,
+    ActionRedone,
+    /// Follows the event that decided the winner.
+    GameCompleted { winner: Winner, completed_at: DateTime<Utc> },
+}
+
+impl GameEvent {
+    /// The event that opens the stream of a new game.
+    pub fn started(game: &Game) -> Self {
+        GameEvent::GameStarted {
+            game_id: game.id(),
+            dealer: game.current_dealer(),
+            rule_set: game.rule_set().clone(),
+            roster: game.roster().clone(),
+            created_at: game.created_at(),
+        }
+    }
+
+    /// The events that open the stream of a game built outside the store:
+    /// its start followed by anything already done to it.
+    pub fn stream(game: &Game) -> Vec<Self> {
+        std::iter::once(Self::started(game)).chain(game.changes().iter().cloned()).collect()
+    }
+
+    /// The action undo reverts for this event, if it is one a player took.
+    pub fn action(&self) -> Option<GameAction> {
+        match self {
+            GameEvent::HandStarted { .. } => Some(GameAction::StartHand),
+            GameEvent::BidRecorded { .. } | GameEvent::CallRecorded { .. } => Some(GameAction::Bid),
+            GameEvent::AllPassed => Some(GameAction::AllPass),
+            GameEvent::WidowRecorded { .. } => Some(GameAction::Widow),
+            GameEvent::TrumpDeclared { .. } => Some(GameAction::DeclareTrump),
+            GameEvent::MeldRecorded { .. } => Some(GameAction::Meld),
+            GameEvent::TricksRecorded { .. } => Some(GameAction::Tricks),
+            GameEvent::PlayStarted { .. } => Some(GameAction::StartPlay),
+            GameEvent::CardPlayed { .. } => Some(GameAction::PlayCard),
+            GameEvent::PlayerSubstituted { .. } => Some(GameAction::Substitute),
+            GameEvent::HandCorrected { .. } => Some(GameAction::EditHand),
+            GameEvent::GameStarted { .. }
+            | GameEvent::ActionUndone
+            | GameEvent::ActionRedone
+            | GameEvent::GameCompleted { .. } => None,
+        }
+    }
+}
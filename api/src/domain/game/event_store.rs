@@ -0,0 +1,25 @@
+use chrono::{DateTime, Utc};
+use crate::domain::game::{GameEvent, GameId};
+
+/// Where the events of every game are kept, one stream per game. A stream
+/// is opened by its `GameStarted` event and only ever appended to.
+#[async_trait::async_trait]
+pub trait EventStore: Send + Sync {
+    /// Appends `events` to the game's stream, provided it still holds the
+    /// `expected_version` events it was read at; a new stream expects 0.
+    async fn append(&self, game_id: GameId, expected_version: usize, events: Vec<GameEvent>) -> Result<(), EventStoreError>;
+    async fn load(&self, game_id: GameId) -> Result<Vec<GameEvent>, EventStoreError>;
+    async fn game_ids(&self) -> Result<Vec<GameId>, EventStoreError>;
+    /// When events were last appended to the game's stream.
+    async fn updated_at(&self, game_id: GameId) -> Result<DateTime<Utc>, EventStoreError>;
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum EventStoreError {
+    #[error("No events for game: {0}")]
+    StreamDoesNotExist(GameId),
+    #[error("Game has already been started: {0}")]
+    StreamAlreadyExists(GameId),
+    #[error("Game {game_id} was changed meanwhile: expected {expected} events, found {actual}")]
+    Conflict { game_id: GameId, expected: usize, actual: usize },
+}
@@ -1,5 +1,14 @@
-use crate::domain::{GameId, GameState, Hand, HandState, Player, Suit, Team};
-use crate::domain::game::GameError;
+use std::collections::{BTreeMap, HashMap};
+use chrono::{DateTime, Utc};
+use crate::domain::{Call, Card, MeldEntry, MeldItem, GameId, GameState, Hand, HandCorrection, HandId, HandRecord, HandState, Player, PlayerProfile, Roster, RuleSet, SeatAssignment, Suit, Team, TeamId, TricksEntry, Winner};
+use crate::domain::game::{GameAction, GameError, GameEvent, GameHistory, HandEdit};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameResult {
+    pub winner: Winner,
+    pub winning_hand: HandId,
+    pub completed_at: DateTime<Utc>,
+}
 
 #[derive(Debug, Clone,PartialEq,Eq)]
 pub struct Game {
@@ -8,6 +17,14 @@ pub struct Game {
     state: GameState,
     completed_hands: Vec<Hand>,
     current_hand: Option<Hand>,
+    rule_set: RuleSet,
+    result: Option<GameResult>,
+    roster: Roster,
+    created_at: DateTime<Utc>,
+    history: GameHistory,
+    edits: Vec<HandEdit>,
+    changes: Vec<GameEvent>,
+    version: usize,
 }
 
 
@@ -19,10 +36,122 @@ impl Game {
             current_dealer: dealer,
             state: GameState::WaitingToStart,
             completed_hands: Vec::new(),
-            current_hand: None
+            current_hand: None,
+            rule_set: RuleSet::default(),
+            result: None,
+            roster: Roster::default(),
+            created_at: Utc::now(),
+            history: GameHistory::default(),
+            edits: Vec::new(),
+            changes: Vec::new(),
+            version: 0,
+        }
+    }
+
+    /// Rebuilds a game from its events, oldest first.
+    pub fn from_events(events: &[GameEvent]) -> Result<Self, GameError> {
+        let Some((GameEvent::GameStarted { game_id, dealer, rule_set, roster, created_at }, rest)) = events.split_first() else {
+            return Err(GameError::InvalidOperation("A game's events must open with GameStarted".to_string()));
+        };
+
+        let game = Game {
+            id: *game_id,
+            created_at: *created_at,
+            ..Game::new(*dealer).with_rule_set(rule_set.clone()).with_roster(roster.clone())
+        };
+        let game = rest.iter().try_fold(game, |game, event| game.apply(event))?;
+
+        Ok(Game {
+            version: events.len(),
+            ..game.committed()
+        })
+    }
+
+    /// Replays `events` to the game as it stood when hand `hand_number` was
+    /// dealt or, given `after`, once that hand's last `after` action was taken.
+    /// Only actions that still stand count: the whole stream is replayed
+    /// first, and the hand is found among the actions its history keeps.
+    pub fn as_of(events: &[GameEvent], hand_number: usize, after: Option<GameAction>) -> Result<Self, GameError> {
+        let kept = Game::from_events(events)?.history.events();
+        let events = if kept.is_empty() { events } else { &kept };
+        let Some((started, rest)) = events.split_first() else {
+            return Err(GameError::InvalidOperation("A game's events must open with GameStarted".to_string()));
+        };
+        let in_hand = |game: &Game| game.current_hand.is_some() && game.current_hand_number() == hand_number;
+
+        let mut game = Game::from_events(std::slice::from_ref(started))?;
+        let mut found = None;
+        for event in rest {
+            if game.current_hand_number() > hand_number {
+                break;
+            }
+            let next = game.apply(event)?;
+            let dealt = !in_hand(&game) && in_hand(&next);
+            match after {
+                None if dealt => return Ok(next.committed()),
+                Some(action) if (dealt || in_hand(&game)) && event.action() == Some(action) => found = Some(next.clone()),
+                _ => {},
+            }
+            game = next;
+        }
+
+        match after {
+            Some(action) => found
+                .map(Game::committed)
+                .ok_or_else(|| GameError::NotReached(format!("{:?} in hand {}", action, hand_number))),
+            None => Err(GameError::NotReached(format!("hand {}", hand_number))),
+        }
+    }
+
+    /// The game once its changes have been stored, with none left pending.
+    pub fn committed(self) -> Self {
+        Game {
+            changes: Vec::new(),
+            ..self
+        }
+    }
+
+    /// Replays one event the way the action behind it was taken, keeping the
+    /// ids and times it carries.
+    fn apply(&self, event: &GameEvent) -> Result<Self, GameError> {
+        match event {
+            GameEvent::GameStarted { game_id, .. } => Err(GameError::InvalidOperation(format!("Game {} has already started", game_id))),
+            GameEvent::HandStarted { hand_id, seed } => self.deal_next_hand(*hand_id, *seed),
+            GameEvent::BidRecorded { bidder, amount } => self.record_bid(*bidder, *amount),
+            GameEvent::CallRecorded { player, call } => self.record_call(*player, *call),
+            GameEvent::AllPassed => self.record_all_pass(),
+            GameEvent::WidowRecorded { widow } => self.record_widow(widow.clone()),
+            GameEvent::TrumpDeclared { trump } => self.declare_trump(*trump),
+            GameEvent::MeldRecorded { meld } => self.record_meld_entry(meld.clone()),
+            GameEvent::TricksRecorded { tricks } => self.record_tricks_entry(tricks.clone()),
+            GameEvent::PlayStarted { hands } => self.start_play(hands.clone()),
+            GameEvent::CardPlayed { player, card } => self.play_card(*player, *card),
+            GameEvent::PlayerSubstituted { seat, player, from_hand } => self.substitute(*seat, player.clone(), *from_hand),
+            GameEvent::HandCorrected { hand_id, correction, edited_at } => self.correct_hand(*hand_id, correction, *edited_at),
+            GameEvent::ActionUndone => self.undo(),
+            GameEvent::ActionRedone => self.redo(),
+            GameEvent::GameCompleted { winner, completed_at } => {
+                let result = self.result
+                    .ok_or_else(|| GameError::InvalidOperation(format!("Game {} has not been won", self.id)))?;
+                Ok(Game {
+                    result: Some(GameResult { winner: *winner, completed_at: *completed_at, ..result }),
+                    history: self.history.with_result(event.clone()),
+                    ..self.clone()
+                })
+            },
         }
     }
 
+    pub fn with_rule_set(mut self, rule_set: RuleSet) -> Self {
+        self.rule_set = rule_set;
+        self
+    }
+
+    pub fn with_roster(mut self, roster: Roster) -> Self {
+        self.roster = roster;
+        self
+    }
+
     pub fn id(&self) -> GameId {
         self.id
     }
@@ -43,89 +172,420 @@ impl Game {
         self.current_hand.clone()
     }
 
-    pub fn with_current_hand(mut self, hand: Option<Hand>) -> Self {
-        self.current_hand = hand;
-        self
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
     }
 
-    pub fn with_state(mut self, state: GameState) -> Self {
-        self.state = state;
-        self
+    pub fn result(&self) -> Option<GameResult> {
+        self.result
+    }
+
+    pub fn roster(&self) -> &Roster {
+        &self.roster
     }
 
+    pub fn created_at(&self) -> DateTime<Utc> {
+        self.created_at
+    }
+
+    pub fn history(&self) -> &GameHistory {
+        &self.history
+    }
+
+    /// The number of stored events the game was built from, which its
+    /// changes are appended after.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    /// Events raised since the game was built from its stored events, to be
+    /// appended to them.
+    pub fn changes(&self) -> &[GameEvent] {
+        &self.changes
+    }
+
+    /// Corrections made to completed hands, oldest first.
+    pub fn edits(&self) -> &[HandEdit] {
+        &self.edits
+    }
+
+    /// Every hand that has been scored, including a completed hand that has
+    /// not yet been followed by a new deal.
+    pub fn scored_hands(&self) -> Vec<Hand> {
+        let mut hands = self.completed_hands.clone();
+        if let Some(hand) = &self.current_hand && matches!(hand.state(), HandState::Completed { .. }) {
+            hands.push(hand.clone());
+        }
+        hands
+    }
+
+    /// The team that won, the winning player's team at an individual table.
+    pub fn winning_team(&self) -> Option<TeamId> {
+        self.result.and_then(|result| match result.winner {
+            Winner::Team(team) => Some(team),
+            Winner::Player(player) => self.rule_set.table.team_of(player),
+        })
+    }
+
+    /// Whether this game, once `before` was completed, no longer stands as
+    /// it was rated: reopened, won by someone else, or won by other totals.
+    pub fn revises_result_of(&self, before: &Game) -> bool {
+        before.state == GameState::Completed
+            && (self.result != before.result || self.team_totals() != before.team_totals())
+    }
+
+    /// Whether this game, once `before` was completed, takes the win away
+    /// from the team that had it.
+    pub fn changes_winner_of(&self, before: &Game) -> bool {
+        before.state == GameState::Completed && self.winning_team() != before.winning_team()
+    }
+
+    /// The players on each team in the last scored hand, or none when a seat
+    /// had no player assigned.
+    pub fn seated_teams(&self) -> Option<Vec<(TeamId, Vec<PlayerProfile>)>> {
+        let hands = self.scored_hands();
+        let seating = hands.last()?.players();
+
+        self.rule_set.table.teams()
+            .iter()
+            .map(|team| {
+                let players = team.seats.iter().map(|seat| seating.get(seat).cloned()).collect::<Option<Vec<_>>>()?;
+                Some((team.id, players))
+            })
+            .collect()
+    }
+
+    /// The number of the hand being played, counting from one.
+    pub fn current_hand_number(&self) -> usize {
+        self.completed_hands.len() + 1
+    }
+
+    /// The game as read back from a stream holding `version` events.
+    #[cfg(test)]
+    pub(crate) fn with_version(self, version: usize) -> Self {
+        Self {
+            version,
+            ..self
+        }
+    }
+
+    /// A game that has already played `completed_hands`, for tests of the
+    /// reports built from finished games.
+    #[cfg(test)]
     pub(crate) fn with_completed_hands(&self, completed_hands: Vec<Hand>) -> Self {
         Self {
             completed_hands,
             ..self.clone()
         }
     }
-    
-    fn next_dealer(&self) -> Player {
-        self.current_dealer.next_clockwise()
+
+    fn next_dealer(&self) -> Option<Player> {
+        self.rule_set.table.next_clockwise(self.current_dealer)
     }
 
     pub fn start_new_hand(&self) -> Result<Self, GameError> {
-        if self.state == GameState::Completed {
-            return Err(GameError::InvalidStateTransition(
-                "Cannot start new hand when game is already in progress".to_string()
-            ));
-        }
-        
-        match self.current_hand.clone() {
+        self.deal_next_hand(HandId::new(), None)
+    }
+
+    /// Starts a new hand shuffled with `seed`, so it can be redealt exactly.
+    pub fn start_new_seeded_hand(&self, seed: u64) -> Result<Self, GameError> {
+        self.deal_next_hand(HandId::new(), Some(seed))
+    }
+
+    /// Files the current hand with the completed hands and deals the next,
+    /// unless the hand was thrown in, when the same dealer deals again. The
+    /// new hand takes `hand_id`.
+    fn deal_next_hand(&self, hand_id: HandId, seed: Option<u64>) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
+
+        let (game, dealer) = match self.current_hand.clone() {
+            Some(hand) if hand.state() == HandState::Redealt => {
+                tracing::info!("Hand {:?} was thrown in, {:?} deals again", hand.id(), self.current_dealer);
+                (self.clone(), Some(self.current_dealer))
+            },
             Some(hand) => {
-                Ok(self.complete_hand_and_start_new(hand))
+                tracing::info!("Completing hand: {:?}", hand.id());
+                let mut completed_hands = self.completed_hands.clone();
+                completed_hands.push(hand);
+                (Game { completed_hands, ..self.clone() }, self.next_dealer())
             },
-            None => {
-                let new_hand = Hand::new(self.current_dealer);
-                Ok(
-                    Self {
-                        state: GameState::InProgress,
-                        current_hand: Some(new_hand),
-                        ..self.clone()
-                    }
-                )
-            }
+            None => (Game { state: GameState::InProgress, ..self.clone() }, Some(self.current_dealer)),
+        };
+        let dealer = dealer
+            .filter(|dealer| self.rule_set.table.seats().contains(dealer))
+            .ok_or_else(|| GameError::InvalidSeating(format!("{} is not seated at this table", self.current_dealer)))?;
+
+        let hand = game.deal_hand(dealer, game.current_hand_number()).with_id(hand_id);
+        let hand = match seed {
+            Some(seed) => hand.with_seed(seed),
+            None => hand,
+        };
+
+        Ok(self.recorded(GameEvent::HandStarted { hand_id: hand.id(), seed }, Game {
+            current_dealer: dealer,
+            current_hand: Some(hand),
+            ..game
+        }))
+    }
+
+    /// Reverts the last action, including the start of a hand, which brings
+    /// back the previous hand and dealer. A game that was won can be undone
+    /// back into play. The game is rebuilt from the events of the actions
+    /// that remain.
+    pub fn undo(&self) -> Result<Self, GameError> {
+        let (action, events, history) = self.history
+            .undo()
+            .ok_or(GameError::NothingToUndo)?;
+
+        tracing::info!("Undoing {:?} on game {}", action, self.id);
+        let previous = Game::from_events(&events)?;
+        Ok(Game {
+            history,
+            changes: self.changes_with(GameEvent::ActionUndone),
+            version: self.version,
+            ..previous
+        })
+    }
+
+    /// Reapplies the last undone action from the events it raised.
+    pub fn redo(&self) -> Result<Self, GameError> {
+        let (action, events, history) = self.history
+            .redo()
+            .ok_or(GameError::NothingToRedo)?;
+
+        tracing::info!("Redoing {:?} on game {}", action, self.id);
+        let next = events.iter().try_fold(self.without_history(), |game, event| game.apply(event))?;
+        Ok(Game {
+            history,
+            changes: self.changes_with(GameEvent::ActionRedone),
+            ..next
+        })
+    }
+
+    /// Rescores completed hand `hand_id` with `correction` and records the
+    /// edit. The winner is decided again from the corrected totals: a game
+    /// that no longer has one is reopened with the next deal, and a game in
+    /// progress that now has one is closed.
+    pub fn edit_completed_hand(&self, hand_id: HandId, correction: &HandCorrection) -> Result<Self, GameError> {
+        self.correct_hand(hand_id, correction, Utc::now())
+    }
+
+    fn correct_hand(&self, hand_id: HandId, correction: &HandCorrection, edited_at: DateTime<Utc>) -> Result<Self, GameError> {
+        let index = self.completed_hands
+            .iter()
+            .position(|hand| hand.id() == hand_id)
+            .ok_or(GameError::HandNotFound(hand_id))?;
+
+        let hand = &self.completed_hands[index];
+        let corrected = hand.rescore(correction)?;
+        let (Some(before), Some(after)) = (HandRecord::of(hand), HandRecord::of(&corrected)) else {
+            return Err(GameError::InvalidOperation(format!("Hand {} is not completed", hand_id)));
+        };
+
+        let mut completed_hands = self.completed_hands.clone();
+        completed_hands[index] = corrected;
+        let mut edits = self.edits.clone();
+        edits.push(HandEdit { hand_id, hand_number: index + 1, before, after, edited_at });
+
+        tracing::info!("Corrected hand {} of game {}", index + 1, self.id);
+        let edited = Game {
+            completed_hands,
+            edits,
+            result: None,
+            ..self.clone()
+        };
+
+        let decided = edited.decide_winner(self.result);
+        if let Some(hand) = &self.current_hand && decided.result.is_some() && self.result.is_none() && !matches!(hand.state(), HandState::Completed { .. }) {
+            return Err(GameError::HandInProgress(hand.id()));
+        }
+
+        let event = GameEvent::HandCorrected { hand_id, correction: correction.clone(), edited_at };
+        Ok(self.recorded(event, decided))
+    }
+
+    /// Settles the game on its scored hands, keeping `previous` when the
+    /// same side still wins. A scored hand not yet moved on from is kept as
+    /// the winning hand.
+    fn decide_winner(self, previous: Option<GameResult>) -> Self {
+        match (self.winner(), previous) {
+            (Some(winner), Some(result)) => Game {
+                result: Some(GameResult { winner, ..result }),
+                ..self
+            },
+            (Some(winner), None) => {
+                tracing::info!("Game {} won by {:?} after a correction", self.id, winner);
+                let completed_hands = self.scored_hands();
+                let winning_hand = completed_hands.last().map(|hand| hand.id()).unwrap_or_else(HandId::new);
+                Game {
+                    state: GameState::Completed,
+                    current_hand: None,
+                    completed_hands,
+                    result: Some(GameResult { winner, winning_hand, completed_at: Utc::now() }),
+                    ..self
+                }
+            },
+            (None, Some(_)) => {
+                tracing::info!("Game {} reopened after a correction", self.id);
+                Game {
+                    state: GameState::InProgress,
+                    current_dealer: self.next_dealer().unwrap_or(self.current_dealer),
+                    ..self
+                }
+            },
+            (None, None) => self,
+        }
+    }
+
+    /// `next`, with `event` raised and kept in the history as the action
+    /// taken on this game. A result the event brought about follows as
+    /// `GameCompleted`.
+    fn recorded(&self, event: GameEvent, next: Game) -> Game {
+        let mut events = vec![event];
+        if let Some(result) = next.result && next.result != self.result {
+            events.push(GameEvent::GameCompleted { winner: result.winner, completed_at: result.completed_at });
+        }
+        let history = match events[0].action() {
+            Some(action) => self.history.record(action, events.clone(), self),
+            None => self.history.clone(),
+        };
+
+        let mut changes = self.changes.clone();
+        changes.extend(events);
+
+        Game {
+            history,
+            changes,
+            ..next
+        }
+    }
+
+    fn changes_with(&self, event: GameEvent) -> Vec<GameEvent> {
+        let mut changes = self.changes.clone();
+        changes.push(event);
+        changes
+    }
+
+    /// The game as it was, without the history and changes that are kept
+    /// alongside it.
+    fn without_history(&self) -> Game {
+        Game {
+            history: GameHistory::default(),
+            changes: Vec::new(),
+            ..self.clone()
         }
     }
 
     pub fn running_totals(&self) -> (i32, i32) {
-        let mut us_total = 0;
-        let mut them_total = 0;
-        for hand in &self.completed_hands {
-            us_total += hand.us_total();
-            them_total += hand.them_total();
+        let totals = self.team_totals();
+        let total = |team: Option<TeamId>| team.and_then(|team| totals.get(&team).copied()).unwrap_or(0);
+        (
+            total(self.rule_set.table.team_id(Team::Us)),
+            total(self.rule_set.table.team_id(Team::Them)),
+        )
+    }
+
+    /// Running total of every team at the table, counting every scored hand.
+    pub fn team_totals(&self) -> BTreeMap<TeamId, i32> {
+        let hands = self.scored_hands();
+        self.rule_set.table.team_ids()
+            .map(|team| (team, hands.iter().map(|hand| hand.team_total(team)).sum()))
+            .collect()
+    }
+
+    /// The team ahead on the running totals, none while the lead is shared.
+    pub fn leading_team(&self) -> Option<TeamId> {
+        let totals = self.team_totals();
+        let best = totals.values().max()?;
+        let mut leaders = totals.iter().filter(|(_, total)| *total == best);
+        match (leaders.next(), leaders.next()) {
+            (Some((team, _)), None) => Some(*team),
+            _ => None,
         }
+    }
+
+    /// The Us and Them running totals after each scored hand, in order.
+    pub fn running_totals_by_hand(&self) -> Vec<(i32, i32)> {
+        let (us, them) = (self.rule_set.table.team_id(Team::Us), self.rule_set.table.team_id(Team::Them));
+        let total = |hand: &Hand, team: Option<TeamId>| team.map(|team| hand.team_total(team)).unwrap_or(0);
+
+        self.scored_hands()
+            .iter()
+            .scan((0, 0), |(us_total, them_total), hand| {
+                *us_total += total(hand, us);
+                *them_total += total(hand, them);
+                Some((*us_total, *them_total))
+            })
+            .collect()
+    }
 
-        (us_total, them_total)
+    /// Running total of every seat, each player carrying their team's score.
+    pub fn player_totals(&self) -> HashMap<Player, i32> {
+        let totals = self.team_totals();
+        self.rule_set.table.seats()
+            .iter()
+            .map(|player| (*player, self.rule_set.table.team_of(*player).and_then(|team| totals.get(&team)).copied().unwrap_or(0)))
+            .collect()
     }
 
     pub fn record_bid(&self, bidder: Player, amount: u32) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
         let current_hand = self.current_hand
             .as_ref()
             .ok_or_else(|| GameError::InvalidOperation("No current hand".to_string()))?;
 
         let new_hand = current_hand.place_bid(bidder, amount)?;
 
-        Ok(Game {
+        Ok(self.recorded(GameEvent::BidRecorded { bidder, amount }, Game {
             current_hand: Some(new_hand),
             ..self.clone()
-        })
+        }))
+    }
+
+    pub fn record_call(&self, player: Player, call: Call) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
+        let current_hand = self.current_hand
+            .as_ref()
+            .ok_or_else(|| GameError::InvalidOperation("No current hand".to_string()))?;
+
+        let new_hand = current_hand.record_call(player, call)?;
+
+        Ok(self.recorded(GameEvent::CallRecorded { player, call }, Game {
+            current_hand: Some(new_hand),
+            ..self.clone()
+        }))
+    }
+
+    pub fn record_all_pass(&self) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
+        let current_hand = self.current_hand
+            .as_ref()
+            .ok_or_else(|| GameError::InvalidOperation("No current hand".to_string()))?;
+
+        let new_hand = current_hand.all_pass()?;
+
+        Ok(self.recorded(GameEvent::AllPassed, Game {
+            current_hand: Some(new_hand),
+            ..self.clone()
+        }))
     }
 
     pub fn declare_trump(&self, trump: Suit) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
         let current_hand = self.current_hand
             .as_ref()
             .ok_or_else(|| GameError::InvalidOperation("No current hand".to_string()))?;
 
         let new_hand = current_hand.declare_trump(trump)?;
 
-        Ok(Game {
+        Ok(self.recorded(GameEvent::TrumpDeclared { trump }, Game {
             current_hand: Some(new_hand),
             ..self.clone()
-        })
+        }))
     }
 
     pub fn record_meld(&self, us: u32, them: u32) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
         let current_hand = self.current_hand
             .as_ref()
             .ok_or_else(|| GameError::InvalidOperation("No current hand".to_string()))?;
@@ -133,13 +593,76 @@ impl Game {
         let new_hand = current_hand.clone().record_meld(us, them)?;
 
         
-        Ok(Game {
+        Ok(self.recorded(GameEvent::MeldRecorded { meld: MeldEntry::Totals { us, them } }, Game {
             current_hand: Some(new_hand),
             ..self.clone()
-        })
+        }.finalize_if_won()))
+    }
+
+    pub fn record_meld_itemized(&self, us: u32, them: u32, items: HashMap<Player, Vec<MeldItem>>) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
+        let current_hand = self.current_hand
+            .as_ref()
+            .ok_or_else(|| GameError::InvalidOperation("No current hand".to_string()))?;
+
+        let recorded_items = items.clone();
+        let new_hand = current_hand.clone().record_meld_itemized(us, them, items)?;
+
+        Ok(self.recorded(GameEvent::MeldRecorded { meld: MeldEntry::Itemized { us, them, items: recorded_items } }, Game {
+            current_hand: Some(new_hand),
+            ..self.clone()
+        }.finalize_if_won()))
+    }
+
+    pub fn record_meld_by_player(&self, by_player: HashMap<Player, u32>) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
+        let current_hand = self.current_hand
+            .as_ref()
+            .ok_or_else(|| GameError::InvalidOperation("No current hand".to_string()))?;
+
+        let new_hand = current_hand.clone().record_meld_by_player(by_player.clone())?;
+
+        Ok(self.recorded(GameEvent::MeldRecorded { meld: MeldEntry::ByPlayer(by_player) }, Game {
+            current_hand: Some(new_hand),
+            ..self.clone()
+        }.finalize_if_won()))
+    }
+
+    pub fn record_meld_cards(&self, cards: &HashMap<Player, Vec<Card>>) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
+        let current_hand = self.current_hand
+            .as_ref()
+            .ok_or_else(|| GameError::InvalidOperation("No current hand".to_string()))?;
+
+        let new_hand = current_hand.clone().record_meld_cards(cards)?;
+
+        Ok(self.recorded(GameEvent::MeldRecorded { meld: MeldEntry::Cards(cards.clone()) }, Game {
+            current_hand: Some(new_hand),
+            ..self.clone()
+        }.finalize_if_won()))
+    }
+
+    /// Records meld entered in any of the ways a table can enter it.
+    pub fn record_meld_entry(&self, entry: MeldEntry) -> Result<Self, GameError> {
+        match entry {
+            MeldEntry::Totals { us, them } => self.record_meld(us, them),
+            MeldEntry::Itemized { us, them, items } => self.record_meld_itemized(us, them, items),
+            MeldEntry::ByPlayer(by_player) => self.record_meld_by_player(by_player),
+            MeldEntry::Cards(cards) => self.record_meld_cards(&cards),
+        }
+    }
+
+    /// Records trick points entered in any of the ways a table can enter them.
+    pub fn record_tricks_entry(&self, entry: TricksEntry) -> Result<Self, GameError> {
+        match entry {
+            TricksEntry::Totals { us, them } => self.record_tricks(us, them),
+            TricksEntry::ByPlayer(by_player) => self.record_tricks_by_player(by_player),
+            TricksEntry::Counters { us, them, last_trick } => self.record_counters(us, them, last_trick),
+        }
     }
 
     pub fn record_tricks(&self, us: u32, them: u32) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
         let current_hand = self.current_hand
             .as_ref()
             .ok_or_else(|| GameError::InvalidOperation("No current hand to record tricks".to_string()))?;
@@ -147,55 +670,200 @@ impl Game {
         tracing::info!("Recording tricks: {0} {1}", us, them);
         let new_hand = current_hand.clone().record_tricks(us, them)?;
         
-        Ok(Game {
+        Ok(self.recorded(GameEvent::TricksRecorded { tricks: TricksEntry::Totals { us, them } }, Game {
             current_hand: Some(new_hand),
             ..self.clone()
-        })
+        }.finalize_if_won()))
     }
 
-    fn complete_hand_and_start_new(&self, new_hand: Hand) -> Game {
-        tracing::info!("Completing hand: {:?}", new_hand);
-        
-        tracing::info!("Completed hands: {0}", self.completed_hands.len());
-        let mut new_completed_hands = self.completed_hands.clone();
-        new_completed_hands.push(new_hand);
+    pub fn record_counters(&self, us: u32, them: u32, last_trick: Team) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
+        let current_hand = self.current_hand
+            .as_ref()
+            .ok_or_else(|| GameError::InvalidOperation("No current hand to record tricks".to_string()))?;
 
-        let next_dealer = self.next_dealer();
-        let new_current_hand = Hand::new(next_dealer);
-        
-        tracing::info!("Completed hands: {:?}", new_completed_hands.len());
+        let new_hand = current_hand.clone().record_counters(us, them, last_trick)?;
 
-        Game {
-            completed_hands: new_completed_hands,
-            current_hand: Some(new_current_hand),
-            current_dealer: next_dealer,
+        Ok(self.recorded(GameEvent::TricksRecorded { tricks: TricksEntry::Counters { us, them, last_trick } }, Game {
+            current_hand: Some(new_hand),
+            ..self.clone()
+        }.finalize_if_won()))
+    }
+
+    pub fn record_tricks_by_player(&self, by_player: HashMap<Player, u32>) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
+        let current_hand = self.current_hand
+            .as_ref()
+            .ok_or_else(|| GameError::InvalidOperation("No current hand to record tricks".to_string()))?;
+
+        let new_hand = current_hand.clone().record_tricks_by_player(by_player.clone())?;
+
+        Ok(self.recorded(GameEvent::TricksRecorded { tricks: TricksEntry::ByPlayer(by_player) }, Game {
+            current_hand: Some(new_hand),
+            ..self.clone()
+        }.finalize_if_won()))
+    }
+
+    /// Starts trick-by-trick play. Without `hands`, a seeded hand is played
+    /// with the cards it was dealt.
+    pub fn start_play(&self, hands: Option<HashMap<Player, Vec<Card>>>) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
+        let current_hand = self.current_hand
+            .as_ref()
+            .ok_or_else(|| GameError::InvalidOperation("No current hand to play".to_string()))?;
+
+        let entered = hands.clone();
+        let hands = hands
+            .or_else(|| current_hand.dealt_cards().map(|deal| deal.hands))
+            .ok_or_else(|| GameError::InvalidOperation("No cards were dealt for this hand".to_string()))?;
+        let new_hand = current_hand.clone().start_play(hands)?;
+
+        Ok(self.recorded(GameEvent::PlayStarted { hands: entered }, Game {
+            current_hand: Some(new_hand),
+            ..self.clone()
+        }))
+    }
+
+    pub fn record_widow(&self, widow: Vec<Card>) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
+        let current_hand = self.current_hand
+            .as_ref()
+            .ok_or_else(|| GameError::InvalidOperation("No current hand".to_string()))?;
+
+        let new_hand = current_hand.clone().record_widow(widow.clone())?;
+
+        Ok(self.recorded(GameEvent::WidowRecorded { widow }, Game {
+            current_hand: Some(new_hand),
+            ..self.clone()
+        }))
+    }
+
+    pub fn play_card(&self, player: Player, card: Card) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
+        let current_hand = self.current_hand
+            .as_ref()
+            .ok_or_else(|| GameError::InvalidOperation("No current hand to play".to_string()))?;
+
+        let new_hand = current_hand.clone().play_card(player, card)?;
+
+        Ok(self.recorded(GameEvent::CardPlayed { player, card }, Game {
+            current_hand: Some(new_hand),
+            ..self.clone()
+        }.finalize_if_won()))
+    }
+
+    /// Seats `player` in `seat` from hand `from_hand` onwards, by default
+    /// from the current hand. Completed hands keep the players who played
+    /// them.
+    pub fn substitute(&self, seat: Player, player: PlayerProfile, from_hand: Option<usize>) -> Result<Self, GameError> {
+        self.ensure_not_over()?;
+        let current = self.current_hand_number();
+        let entered = from_hand;
+        let from_hand = from_hand.unwrap_or(current);
+
+        if from_hand < current {
+            return Err(GameError::InvalidSeating(format!("Hand {} has already been played", from_hand)));
+        }
+
+        let roster = self.roster.with_assignment(SeatAssignment { seat, player: player.clone(), from_hand });
+        roster.validate(&self.rule_set.table).map_err(GameError::InvalidSeating)?;
+
+        let current_hand = match from_hand == current {
+            true => self.current_hand.clone().map(|hand| hand.with_players(roster.seating(current))),
+            false => self.current_hand.clone(),
+        };
+
+        Ok(self.recorded(GameEvent::PlayerSubstituted { seat, player: player.clone(), from_hand: entered }, Self {
+            roster,
+            current_hand,
             ..self.clone()
+        }))
+    }
+
+    fn ensure_not_over(&self) -> Result<(), GameError> {
+        match self.state {
+            GameState::Completed => Err(GameError::GameOver),
+            _ => Ok(())
+        }
+    }
+
+    /// Once the current hand is scored, checks whether it carried a team past
+    /// the rule set's target. If so the hand is filed with the completed hands
+    /// and the game is closed with its result.
+    fn finalize_if_won(self) -> Self {
+        let Some(hand) = self.current_hand.clone() else {
+            return self;
+        };
+
+        if !matches!(hand.state(), HandState::Completed { .. }) {
+            return self;
+        }
+
+        match self.winner() {
+            Some(winner) => {
+                tracing::info!("Game {} won by {:?}", self.id, winner);
+                let mut completed_hands = self.completed_hands.clone();
+                completed_hands.push(hand.clone());
+                Game {
+                    state: GameState::Completed,
+                    current_hand: None,
+                    result: Some(GameResult {
+                        winner,
+                        winning_hand: hand.id(),
+                        completed_at: Utc::now(),
+                    }),
+                    completed_hands,
+                    ..self
+                }
+            },
+            None => self
         }
     }
 
+    fn deal_hand(&self, dealer: Player, hand_number: usize) -> Hand {
+        Hand::new(dealer)
+            .with_rule_set(self.rule_set.clone())
+            .with_players(self.roster.seating(hand_number))
+    }
+
     pub fn is_game_complete(&self) -> bool {
-        let (us_total, them_total) = self.running_totals();
-        us_total >= 500 || them_total >= 500
+        self.team_totals().values().any(|total| self.rule_set.has_reached_target(*total))
     }
     
-    fn bidder_winner_if_applicable(&self) -> Option<Team> {
-        self.completed_hands
+    fn bidder_winner_if_applicable(&self) -> Option<TeamId> {
+        self.scored_hands()
             .last()
-            .and_then(|hand| hand.bidder().map(|bidder| bidder.team()))
+            .and_then(|hand| hand.bidder().and_then(|bidder| self.rule_set.table.team_of(bidder)))
     }
     
-    pub fn winner(&self) -> Option<Team> {
+    /// The team, or the player at an individual table, that won the game.
+    /// When several reach the target on the same hand the bidding side wins,
+    /// otherwise the highest score does.
+    pub fn winner(&self) -> Option<Winner> {
+        if let Some(result) = self.result {
+            return Some(result.winner);
+        }
+
         if !self.is_game_complete() {
             return None;
         }
 
-        let (us_total, them_total) = self.running_totals();
-
-        match (us_total >= 500, them_total >= 500) {
-            (true, false) => Some(Team::Us),
-            (false, true) => Some(Team::Them),
-            (true, true) => self.bidder_winner_if_applicable(),
-            _ => None
+        let totals = self.team_totals();
+        let reached: Vec<TeamId> = totals.iter()
+            .filter(|(_, total)| self.rule_set.has_reached_target(**total))
+            .map(|(team, _)| *team)
+            .collect();
+
+        let team = match reached.as_slice() {
+            [team] => *team,
+            _ => self.bidder_winner_if_applicable()
+                .filter(|bidding_team| reached.contains(bidding_team))
+                .or_else(|| reached.iter().copied().max_by_key(|team| totals[team]))?,
+        };
+
+        match self.rule_set.table.members(team) {
+            [player] => Some(Winner::Player(*player)),
+            _ => Some(Winner::Team(team)),
         }
     }
 }
@@ -203,20 +871,11 @@ impl Game {
 #[cfg(test)]
 impl Game {
     pub fn add_completed_hand_with_scores(&self, us_total: i32, them_total: i32, bidder: Player) -> Self {
-        use crate::domain::HandState;
+        use crate::domain::{HandScore, HandState};
 
         let completed_hand = Hand::new(Player::South).set_state(
-            HandState::Completed {
-                bidder,
-                bid_amount: 51,
-                trump: Suit::Spades,
-                us_meld: Some(20),
-                them_meld: Some(20),
-                us_tricks: Some(25),
-                them_tricks: Some(25),
-                us_total: Some(us_total),
-                them_total: Some(them_total)
-            }
+            HandState::Completed { bidder, bid_amount: 51, trump: Suit::Spades },
+            HandScore::us_them((20, 20), (25, 25), (us_total, them_total)),
         );
 
         let mut new_completed_hands = self.completed_hands.clone();
@@ -228,6 +887,17 @@ impl Game {
         }
     }
     
+    /// Marks the game won by `winner` on its last scored hand, for fixtures
+    /// of finished games.
+    pub(crate) fn with_winner(&self, winner: Winner, completed_at: DateTime<Utc>) -> Game {
+        let winning_hand = self.scored_hands().last().map(|hand| hand.id()).unwrap_or_else(HandId::new);
+        Game {
+            state: GameState::Completed,
+            result: Some(GameResult { winner, winning_hand, completed_at }),
+            ..self.clone()
+        }
+    }
+
     pub fn completed_state(&self) -> Game {
         Game {
             completed_hands: self.completed_hands.clone(),
@@ -236,9 +906,10 @@ impl Game {
     }
 }
 
+#[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{Player, GameState, HandState, Team};
+    use crate::domain::{AllPassRule, Partnership, Player, PlayerProfile, GameState, HandState, Rank, Roster, Table};
 
     #[test]
     fn new_game_should_start_with_given_dealer() {
@@ -314,16 +985,91 @@ mod tests {
     }
 
     #[test]
-    fn should_propagate_hand_error_for_invalid_bid() {
+    fn should_record_auction_calls_on_current_hand() {
         let game = Game::new(Player::South)
             .start_new_hand()
+            .unwrap()
+            .record_call(Player::West, Call::Bid(50))
+            .unwrap()
+            .record_call(Player::North, Call::Pass)
+            .unwrap()
+            .record_call(Player::East, Call::Pass)
+            .unwrap()
+            .record_call(Player::South, Call::Pass)
             .unwrap();
 
-        let result = game.record_bid(Player::North, 49);
-
-        assert!(result.is_err());
+        let hand = game.current_hand().unwrap();
+        assert_eq!(hand.bidder(), Some(Player::West));
+        assert_eq!(hand.bid_amount(), Some(50));
+        assert_eq!(hand.auction().calls().len(), 4);
+    }
 
-        match result.unwrap_err() {
+    #[test]
+    fn should_stick_dealer_when_everyone_passes() {
+        let game = Game::new(Player::South)
+            .start_new_hand()
+            .unwrap()
+            .record_all_pass()
+            .unwrap();
+
+        let hand = game.current_hand().unwrap();
+        assert_eq!(hand.bidder(), Some(Player::South));
+        assert_eq!(hand.bid_amount(), Some(50));
+    }
+
+    #[test]
+    fn should_not_advance_dealer_after_redeal() {
+        let redeal = RuleSet { all_pass: AllPassRule::Redeal, ..RuleSet::default() };
+        let game = Game::new(Player::South)
+            .with_rule_set(redeal)
+            .start_new_hand()
+            .unwrap()
+            .record_all_pass()
+            .unwrap();
+        let redealt_hand = game.current_hand().unwrap();
+        assert_eq!(redealt_hand.state(), HandState::Redealt);
+
+        let game = game.start_new_hand().unwrap();
+
+        let hand = game.current_hand().unwrap();
+        assert_ne!(hand.id(), redealt_hand.id());
+        assert_eq!(hand.dealer(), Player::South);
+        assert_eq!(hand.state(), HandState::WaitingForBid);
+        assert_eq!(game.current_dealer(), Player::South);
+        assert_eq!(game.completed_hands().len(), 0);
+    }
+
+    #[test]
+    fn should_advance_dealer_after_completed_hand() {
+        let game = Game::new(Player::South)
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .record_tricks(30, 20)
+            .unwrap()
+            .start_new_hand()
+            .unwrap();
+
+        assert_eq!(game.current_dealer(), Player::West);
+        assert_eq!(game.current_hand().unwrap().dealer(), Player::West);
+    }
+
+    #[test]
+    fn should_propagate_hand_error_for_invalid_bid() {
+        let game = Game::new(Player::South)
+            .start_new_hand()
+            .unwrap();
+
+        let result = game.record_bid(Player::North, 49);
+
+        assert!(result.is_err());
+
+        match result.unwrap_err() {
             GameError::HandError(_) => {},
             _ => panic!("Expected HandError to be propagated")
         }
@@ -427,9 +1173,9 @@ mod tests {
 
         let hand = new_game.current_hand().unwrap();
         match hand.state() {
-            HandState::WaitingForTricks { us_meld, them_meld, ..} => {
-                assert_eq!(us_meld, Some(24));
-                assert_eq!(them_meld, Some(32));
+            HandState::WaitingForTricks { .. } => {
+                assert_eq!(hand.us_meld(), Some(24));
+                assert_eq!(hand.them_meld(), Some(32));
             },
             _ => panic!("Expected WaitingForTricks state")
         }
@@ -439,6 +1185,87 @@ mod tests {
         assert_eq!(new_game.completed_hands().len(), 0);
     }
 
+    #[test]
+    fn should_record_meld_from_cards_on_current_hand() {
+        let game = Game::new(Player::South)
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap();
+
+        let aces_around: Vec<Card> = Suit::PLAYABLE.iter().map(|suit| Card::new(Rank::Ace, *suit)).collect();
+        let cards = HashMap::from([(Player::North, aces_around.clone()), (Player::South, aces_around)]);
+
+        let game = game.record_meld_cards(&cards).unwrap();
+
+        let hand = game.current_hand().unwrap();
+        match hand.state() {
+            HandState::WaitingForTricks { .. } => {
+                assert_eq!(hand.us_meld(), Some(20));
+                assert_eq!(hand.them_meld(), None);
+            },
+            _ => panic!("Expected WaitingForTricks state")
+        }
+    }
+
+    #[test]
+    fn should_record_seed_and_redeal_hand_exactly() {
+        let game = Game::new(Player::South)
+            .start_new_seeded_hand(2024)
+            .unwrap();
+
+        let hand = game.current_hand().unwrap();
+        let dealt = hand.dealt_cards().unwrap();
+
+        assert_eq!(hand.seed(), Some(2024));
+        assert_eq!(dealt.hands.len(), 4);
+        assert!(dealt.hands.values().all(|cards| cards.len() == 20));
+        assert_eq!(dealt, game.current_hand().unwrap().dealt_cards().unwrap());
+    }
+
+    #[test]
+    fn should_start_play_with_seeded_deal() {
+        let game = Game::new(Player::South)
+            .start_new_seeded_hand(7)
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .start_play(None)
+            .unwrap();
+
+        let hand = game.current_hand().unwrap();
+        assert!(matches!(hand.state(), HandState::Playing { .. }));
+        assert_eq!(hand.play().unwrap().next_to_play(), Player::North);
+    }
+
+    #[test]
+    fn should_not_deal_from_a_seat_that_is_not_at_the_table() {
+        let game = Game::new(Player::West).with_rule_set(RuleSet::single_deck_cutthroat());
+
+        assert!(matches!(game.start_new_hand(), Err(GameError::InvalidSeating(_))));
+    }
+
+    #[test]
+    fn should_require_cards_to_start_play_without_seed() {
+        let game = Game::new(Player::South)
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap();
+
+        assert!(matches!(game.start_play(None), Err(GameError::InvalidOperation(_))));
+    }
+
     #[test]
     fn should_propagate_hand_meld_validation_errors() {
 
@@ -503,12 +1330,13 @@ mod tests {
         assert!(result.is_ok());
         let new_game = result.unwrap();
         
-        match new_game.current_hand().unwrap().state() {
-            HandState::Completed { us_tricks, them_tricks, us_total, them_total, ..} => {
-                assert_eq!(us_tricks, Some(26));
-                assert_eq!(them_tricks, Some(24));
-                assert_eq!(us_total, Some(-51));
-                assert_eq!(them_total, Some(56));
+        let hand = new_game.current_hand().unwrap();
+        match hand.state() {
+            HandState::Completed { .. } => {
+                assert_eq!(hand.us_tricks(), Some(26));
+                assert_eq!(hand.them_tricks(), Some(24));
+                assert_eq!(hand.us_total(), -51);
+                assert_eq!(hand.them_total(), 56);
             },
             _ => panic!("Expected Completed state")
         }
@@ -552,10 +1380,11 @@ mod tests {
         assert!(result.is_ok());
         let new_game = result.unwrap();
         
-        match new_game.current_hand().unwrap().state() {
-            HandState::Completed { us_total, them_total, .. } => {
-                assert_eq!(us_total, Some(-60));
-                assert_eq!(them_total, Some(52));
+        let hand = new_game.current_hand().unwrap();
+        match hand.state() {
+            HandState::Completed { .. } => {
+                assert_eq!(hand.us_total(), -60);
+                assert_eq!(hand.them_total(), 52);
             },
             _ => panic!("Expected Completed state")
         }
@@ -631,7 +1460,7 @@ mod tests {
             .add_completed_hand_with_scores(250, 150, Player::South);
 
         // US: 550, THEM: 350 - only US reaches 500
-        assert_eq!(game.winner(), Some(Team::Us));
+        assert_eq!(game.winner(), Some(Winner::Team(TeamId::US)));
     }
 
     #[test]
@@ -641,7 +1470,7 @@ mod tests {
             .add_completed_hand_with_scores(150, 250, Player::South);
 
         // US: 350, THEM: 550 - only THEM reaches 500
-        assert_eq!(game.winner(), Some(Team::Them));
+        assert_eq!(game.winner(), Some(Winner::Team(TeamId::THEM)));
     }
 
     #[test]
@@ -651,7 +1480,7 @@ mod tests {
             .add_completed_hand_with_scores(250, 250, Player::East); // East (THEM) was last bidder
 
         // US: 550, THEM: 550 - both reach 500, THEM team bid last
-        assert_eq!(game.winner(), Some(Team::Them));
+        assert_eq!(game.winner(), Some(Winner::Team(TeamId::THEM)));
     }
 
     #[test]
@@ -662,7 +1491,592 @@ mod tests {
 
         // US: 600, THEM: 550 - both reach 500, US has higher score
         // This tests the fallback when we can't determine last bidder
-        assert_eq!(game.winner(), Some(Team::Us));
+        assert_eq!(game.winner(), Some(Winner::Team(TeamId::US)));
+    }
+
+    #[test]
+    fn should_pass_rule_set_to_new_hands() {
+        let game = Game::new(Player::South)
+            .with_rule_set(RuleSet::single_deck_150())
+            .start_new_hand()
+            .unwrap();
+
+        assert_eq!(game.current_hand().unwrap().rule_set(), &RuleSet::single_deck_150());
+        assert!(game.record_bid(Player::North, 21).is_ok());
+    }
+
+    #[test]
+    fn should_complete_game_at_rule_set_target() {
+        let game = Game::new(Player::South)
+            .with_rule_set(RuleSet::single_deck_150())
+            .add_completed_hand_with_scores(100, 40, Player::North)
+            .add_completed_hand_with_scores(50, 30, Player::South);
+
+        assert!(game.is_game_complete());
+        assert_eq!(game.winner(), Some(Winner::Team(TeamId::US)));
+    }
+
+    fn game_to(target: u32) -> Game {
+        Game::new(Player::South).with_rule_set(RuleSet { game_target: target, ..RuleSet::default() })
+    }
+
+    #[test]
+    fn should_finalize_game_when_hand_reaches_target() {
+        let game = game_to(50)
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap();
+        let hand_id = game.current_hand().unwrap().id();
+
+        let game = game.record_tricks(30, 20).unwrap();
+
+        assert_eq!(game.state(), GameState::Completed);
+        assert!(game.current_hand().is_none());
+        assert_eq!(game.completed_hands().len(), 1);
+        assert_eq!(game.running_totals(), (54, 52));
+
+        let result = game.result().unwrap();
+        assert_eq!(result.winner, Winner::Team(TeamId::US));
+        assert_eq!(result.winning_hand, hand_id);
+        assert_eq!(game.winner(), Some(Winner::Team(TeamId::US)));
+    }
+
+    #[test]
+    fn should_finalize_game_when_failed_meld_reaches_target() {
+        let game = game_to(30)
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(10, 32)
+            .unwrap();
+
+        assert_eq!(game.state(), GameState::Completed);
+        assert_eq!(game.winner(), Some(Winner::Team(TeamId::THEM)));
+    }
+
+    #[test]
+    fn should_keep_playing_when_target_not_reached() {
+        let game = Game::new(Player::South)
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .record_tricks(30, 20)
+            .unwrap();
+
+        assert_eq!(game.state(), GameState::InProgress);
+        assert!(game.result().is_none());
+        assert!(game.current_hand().is_some());
+    }
+
+    #[test]
+    fn should_reject_play_once_game_is_over() {
+        let game = game_to(50)
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .record_tricks(30, 20)
+            .unwrap();
+
+        assert!(matches!(game.start_new_hand(), Err(GameError::GameOver)));
+        assert!(matches!(game.record_bid(Player::North, 51), Err(GameError::GameOver)));
+        assert!(matches!(game.declare_trump(Suit::Spades), Err(GameError::GameOver)));
+        assert!(matches!(game.record_meld(20, 20), Err(GameError::GameOver)));
+        assert!(matches!(game.record_tricks(25, 25), Err(GameError::GameOver)));
+    }
+
+    #[test]
+    fn should_rotate_dealer_around_cutthroat_table() {
+        let game = Game::new(Player::South)
+            .with_rule_set(RuleSet::single_deck_cutthroat());
+
+        assert_eq!(game.next_dealer(), Some(Player::North));
+    }
+
+    #[test]
+    fn should_declare_a_player_the_winner_of_a_cutthroat_game() {
+        let game = Game::new(Player::South)
+            .with_rule_set(RuleSet { game_target: 20, ..RuleSet::single_deck_cutthroat() })
+            .start_new_hand()
+            .unwrap()
+            .record_call(Player::North, Call::Bid(25))
+            .unwrap()
+            .record_call(Player::East, Call::Pass)
+            .unwrap()
+            .record_call(Player::South, Call::Pass)
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap()
+            .record_meld_by_player(HashMap::from([(Player::North, 10)]))
+            .unwrap()
+            .record_tricks_by_player(HashMap::from([(Player::North, 20), (Player::East, 3), (Player::South, 2)]))
+            .unwrap();
+
+        assert_eq!(game.state(), GameState::Completed);
+        assert_eq!(game.winner(), Some(Winner::Player(Player::North)));
+        assert_eq!(game.player_totals()[&Player::North], 30);
+        assert_eq!(game.player_totals()[&Player::South], 2);
+    }
+
+    #[test]
+    fn should_key_scores_by_named_partnerships() {
+        let table = Table::default().with_partnerships(vec![
+            Partnership::new(TeamId(7), "Smiths", vec![Player::North, Player::South]),
+            Partnership::new(TeamId(3), "Joneses", vec![Player::East, Player::West]),
+        ]);
+        let game = Game::new(Player::South)
+            .with_rule_set(RuleSet { table, ..RuleSet::default() })
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .record_tricks(30, 20)
+            .unwrap()
+            .start_new_hand()
+            .unwrap();
+
+        assert_eq!(game.team_totals(), BTreeMap::from([(TeamId(3), 52), (TeamId(7), 54)]));
+        assert_eq!(game.running_totals(), (54, 52));
+    }
+
+    fn seated_game() -> Game {
+        Game::new(Player::South).with_roster(Roster::new(vec![
+            (Player::North, PlayerProfile::new("Ann")),
+            (Player::East, PlayerProfile::new("Bob")),
+            (Player::South, PlayerProfile::new("Cat")),
+            (Player::West, PlayerProfile::new("Dan")),
+        ]))
+    }
+
+    #[test]
+    fn should_seat_roster_on_each_hand() {
+        let game = seated_game().start_new_hand().unwrap();
+
+        let hand = game.current_hand().unwrap();
+        assert_eq!(hand.players()[&Player::North].name, "Ann");
+        assert_eq!(hand.players().len(), 4);
+    }
+
+    #[test]
+    fn should_substitute_player_from_current_or_later_hand() {
+        let game = seated_game()
+            .start_new_hand()
+            .unwrap()
+            .substitute(Player::East, PlayerProfile::new("Eve"), Some(2))
+            .unwrap();
+
+        assert_eq!(game.current_hand().unwrap().players()[&Player::East].name, "Bob");
+
+        let game = game
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .record_tricks(30, 20)
+            .unwrap()
+            .start_new_hand()
+            .unwrap();
+
+        assert_eq!(game.current_hand().unwrap().players()[&Player::East].name, "Eve");
+        assert_eq!(game.completed_hands()[0].players()[&Player::East].name, "Bob");
+        assert!(matches!(
+            game.substitute(Player::West, PlayerProfile::new("Fay"), Some(1)),
+            Err(GameError::InvalidSeating(_))
+        ));
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn should_replace_the_player_when_substituting_during_the_first_hand() {
+        let game = seated_game()
+            .start_new_hand()
+            .unwrap()
+            .substitute(Player::East, PlayerProfile::new("Eve"), None)
+            .unwrap();
+
+        assert_eq!(game.current_hand().unwrap().players()[&Player::East].name, "Eve");
+        assert_eq!(game.roster().assignments().iter().filter(|assignment| assignment.seat == Player::East).count(), 1);
+    }
+
+    #[test]
+    fn should_keep_the_last_substitute_for_the_same_upcoming_hand() {
+        let game = seated_game()
+            .start_new_hand()
+            .unwrap()
+            .substitute(Player::East, PlayerProfile::new("Eve"), Some(2))
+            .unwrap()
+            .substitute(Player::East, PlayerProfile::new("Fay"), Some(2))
+            .unwrap();
+
+        assert_eq!(game.roster().player_at(Player::East, 1).unwrap().name, "Bob");
+        assert_eq!(game.roster().player_at(Player::East, 2).unwrap().name, "Fay");
+        assert_eq!(game.roster().assignments().len(), 5);
+    }
+
+    #[test]
+    fn should_undo_and_redo_meld() {
+        let game = Game::new(Player::South)
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap();
+        let melded = game.record_meld(24, 32).unwrap();
+
+        let undone = melded.undo().unwrap();
+
+        assert_eq!(undone.current_hand(), game.current_hand());
+        assert_eq!(undone.history().last_action(), Some(GameAction::DeclareTrump));
+        assert_eq!(undone.history().next_redo(), Some(GameAction::Meld));
+
+        let redone = undone.redo().unwrap();
+
+        assert_eq!(redone.clone().committed(), melded.committed());
+        assert!(matches!(redone.redo(), Err(GameError::NothingToRedo)));
+    }
+
+    #[test]
+    fn should_undo_start_of_hand_back_to_previous_hand_and_dealer() {
+        let scored = Game::new(Player::South)
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .record_tricks(30, 20)
+            .unwrap();
+
+        let undone = scored.start_new_hand().unwrap().undo().unwrap();
+
+        assert_eq!(undone.current_dealer(), Player::South);
+        assert_eq!(undone.current_hand(), scored.current_hand());
+        assert!(undone.completed_hands().is_empty());
+    }
+
+    #[test]
+    fn should_clear_redo_after_a_new_action() {
+        let game = Game::new(Player::South)
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .undo()
+            .unwrap()
+            .record_bid(Player::East, 52)
+            .unwrap();
+
+        assert_eq!(game.history().next_redo(), None);
+        assert_eq!(game.current_hand().unwrap().bid_amount(), Some(52));
+        assert!(matches!(Game::new(Player::South).undo(), Err(GameError::NothingToUndo)));
+    }
+
+    #[test]
+    fn should_undo_the_winning_action_back_into_play_and_redo_the_same_result() {
+        let won = game_with_scored_hand(50);
+        assert_eq!(won.state(), GameState::Completed);
+
+        let undone = won.undo().unwrap();
+
+        assert_eq!(undone.state(), GameState::InProgress);
+        assert!(undone.result().is_none());
+        assert_eq!(undone.current_hand().unwrap().id(), won.completed_hands()[0].id());
+
+        let redone = undone.redo().unwrap();
+
+        assert_eq!(redone.result(), won.result());
+        assert_eq!(redone.changes().last(), Some(&GameEvent::ActionRedone));
+    }
+
+    fn game_with_scored_hand(target: u32) -> Game {
+        game_to(target)
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .record_tricks(30, 20)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_correct_completed_hand_and_record_the_edit() {
+        let game = game_with_scored_hand(500).start_new_hand().unwrap();
+        let hand_id = game.completed_hands()[0].id();
+
+        let game = game.edit_completed_hand(hand_id, &HandCorrection {
+            meld: BTreeMap::from([(TeamId::US, 40)]),
+            ..HandCorrection::default()
+        }).unwrap();
+
+        assert_eq!(game.running_totals(), (70, 52));
+        assert_eq!(game.state(), GameState::InProgress);
+        assert_eq!(game.current_dealer(), Player::West);
+
+        let edit = &game.edits()[0];
+        assert_eq!((edit.hand_id, edit.hand_number), (hand_id, 1));
+        assert_eq!(edit.before.totals[&TeamId::US], 54);
+        assert_eq!(edit.after.meld[&TeamId::US], 40);
+        assert_eq!(game.history().last_action(), Some(GameAction::EditHand));
+        assert!(matches!(game.edit_completed_hand(HandId::new(), &HandCorrection::default()), Err(GameError::HandNotFound(_))));
+    }
+
+    #[test]
+    fn should_change_winner_or_reopen_game_after_correction() {
+        let game = game_with_scored_hand(50);
+        let hand_id = game.completed_hands()[0].id();
+        let completed_at = game.result().unwrap().completed_at;
+
+        let them_win = game.edit_completed_hand(hand_id, &HandCorrection {
+            tricks: BTreeMap::from([(TeamId::US, 20), (TeamId::THEM, 30)]),
+            ..HandCorrection::default()
+        }).unwrap();
+
+        assert_eq!(them_win.state(), GameState::Completed);
+        assert_eq!(them_win.result().unwrap().winner, Winner::Team(TeamId::THEM));
+        assert_eq!(them_win.result().unwrap().completed_at, completed_at);
+
+        let reopened = game.edit_completed_hand(hand_id, &HandCorrection {
+            meld: BTreeMap::from([(TeamId::THEM, 10)]),
+            tricks: BTreeMap::from([(TeamId::US, 20), (TeamId::THEM, 30)]),
+            ..HandCorrection::default()
+        }).unwrap();
+
+        assert_eq!(reopened.state(), GameState::InProgress);
+        assert!(reopened.result().is_none());
+        assert_eq!(reopened.running_totals(), (-51, 30));
+        assert!(reopened.current_hand().is_none());
+        assert_eq!(reopened.current_dealer(), Player::West);
+    }
+
+    #[test]
+    fn should_refuse_a_correction_that_decides_the_game_while_a_hand_is_dealt() {
+        let game = game_with_scored_hand(500).start_new_hand().unwrap();
+        let hand_id = game.completed_hands()[0].id();
+        let winning = HandCorrection { meld: BTreeMap::from([(TeamId::US, 500)]), ..HandCorrection::default() };
+
+        assert!(matches!(game.edit_completed_hand(hand_id, &winning), Err(GameError::HandInProgress(id)) if id == game.current_hand().unwrap().id()));
+
+        let scored = game
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .record_tricks(30, 20)
+            .unwrap();
+        let last_hand = scored.current_hand().unwrap().id();
+
+        let won = scored.edit_completed_hand(hand_id, &winning).unwrap();
+
+        assert_eq!(won.state(), GameState::Completed);
+        assert!(won.current_hand().is_none());
+        assert_eq!(won.completed_hands().len(), 2);
+        assert_eq!(won.result().unwrap().winning_hand, last_hand);
+    }
+
+    #[test]
+    fn should_revise_the_rated_result_when_a_correction_changes_a_completed_game() {
+        let game = game_with_scored_hand(50);
+        let hand_id = game.completed_hands()[0].id();
+        let correct = |correction: HandCorrection| game.edit_completed_hand(hand_id, &correction).unwrap();
+
+        let wider = correct(HandCorrection { meld: BTreeMap::from([(TeamId::US, 40)]), ..HandCorrection::default() });
+        let them_win = correct(HandCorrection { tricks: BTreeMap::from([(TeamId::US, 20), (TeamId::THEM, 30)]), ..HandCorrection::default() });
+
+        assert_eq!(wider.winning_team(), Some(TeamId::US));
+        assert!(wider.revises_result_of(&game));
+        assert!(them_win.revises_result_of(&game));
+        assert!(game.undo().unwrap().revises_result_of(&game));
+        assert!(!game.revises_result_of(&game));
+
+        let in_progress = game_with_scored_hand(500).start_new_hand().unwrap();
+        let edited = in_progress.edit_completed_hand(in_progress.completed_hands()[0].id(), &HandCorrection {
+            meld: BTreeMap::from([(TeamId::US, 40)]),
+            ..HandCorrection::default()
+        }).unwrap();
+        assert!(!edited.revises_result_of(&in_progress));
+    }
+
+    #[test]
+    fn should_change_the_winner_only_when_a_correction_reopens_or_overturns_the_game() {
+        let game = game_with_scored_hand(50);
+        let hand_id = game.completed_hands()[0].id();
+        let correct = |correction: HandCorrection| game.edit_completed_hand(hand_id, &correction).unwrap();
+
+        let wider = correct(HandCorrection { meld: BTreeMap::from([(TeamId::US, 40)]), ..HandCorrection::default() });
+        let them_win = correct(HandCorrection { tricks: BTreeMap::from([(TeamId::US, 20), (TeamId::THEM, 30)]), ..HandCorrection::default() });
+        let reopened = correct(HandCorrection {
+            meld: BTreeMap::from([(TeamId::THEM, 10)]),
+            tricks: BTreeMap::from([(TeamId::US, 20), (TeamId::THEM, 30)]),
+            ..HandCorrection::default()
+        });
+
+        assert!(!wider.changes_winner_of(&game));
+        assert!(them_win.changes_winner_of(&game));
+        assert!(reopened.changes_winner_of(&game));
+        assert!(!game.changes_winner_of(&game.undo().unwrap()));
+    }
+
+    #[test]
+    fn should_rebuild_the_same_game_from_its_events() {
+        let game = game_to(50);
+        let started = GameEvent::started(&game);
+        let game = game
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .undo()
+            .unwrap()
+            .redo()
+            .unwrap()
+            .record_tricks(30, 20)
+            .unwrap();
+        let hand_id = game.completed_hands()[0].id();
+        let game = game.edit_completed_hand(hand_id, &HandCorrection {
+            tricks: BTreeMap::from([(TeamId::US, 20), (TeamId::THEM, 30)]),
+            ..HandCorrection::default()
+        }).unwrap();
+
+        let mut events = vec![started];
+        events.extend(game.changes().iter().cloned());
+        let rebuilt = Game::from_events(&events).unwrap();
+
+        assert_eq!(rebuilt, game.clone().committed().with_version(events.len()));
+        assert_eq!(rebuilt.result().unwrap().winner, Winner::Team(TeamId::THEM));
+        assert!(rebuilt.changes().is_empty());
+        assert!(Game::from_events(&events[1..]).is_err());
+    }
+
+    #[test]
+    fn should_keep_running_totals_after_each_hand() {
+        let game = game_with_scored_hand(500)
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::East, 60)
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap()
+            .record_meld(20, 30)
+            .unwrap()
+            .record_tricks(25, 25)
+            .unwrap()
+            .start_new_hand()
+            .unwrap();
+
+        assert_eq!(game.running_totals_by_hand(), vec![(54, 52), (99, -8)]);
+        assert_eq!(game.running_totals_by_hand().last().copied(), Some(game.running_totals()));
+    }
+
+    #[test]
+    fn should_count_a_scored_hand_before_the_next_deal() {
+        let game = game_with_scored_hand(500);
+
+        assert!(game.completed_hands().is_empty());
+        assert_eq!(game.running_totals_by_hand(), vec![(54, 52)]);
+        assert_eq!(game.running_totals(), (54, 52));
+    }
+
+    #[test]
+    fn should_replay_game_as_of_a_hand_or_an_action_within_it() {
+        let game = game_to(500);
+        let started = GameEvent::started(&game);
+        let game = game
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .record_tricks(30, 20)
+            .unwrap()
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::East, 60)
+            .unwrap();
+        let events = [vec![started], game.changes().to_vec()].concat();
+
+        let first = Game::as_of(&events, 1, None).unwrap();
+        let second = Game::as_of(&events, 2, None).unwrap();
+        let melded = Game::as_of(&events, 1, Some(GameAction::Meld)).unwrap();
+
+        assert_eq!(first.running_totals(), (0, 0));
+        assert_eq!(first.current_hand().unwrap().state(), HandState::WaitingForBid);
+        assert_eq!(second.current_dealer(), Player::West);
+        assert_eq!(second.running_totals(), (54, 52));
+        assert_eq!(second.leading_team(), Some(TeamId::US));
+        assert!(second.current_hand().unwrap().bidder().is_none());
+        assert_eq!(melded.current_hand().unwrap().us_meld(), Some(24));
+        assert!(melded.current_hand().unwrap().us_tricks().is_none());
+        assert!(matches!(Game::as_of(&events, 2, Some(GameAction::Meld)), Err(GameError::NotReached(_))));
+        assert!(matches!(Game::as_of(&events, 3, None), Err(GameError::NotReached(_))));
+    }
+
+    #[test]
+    fn should_replay_game_as_of_an_action_without_the_actions_undone_after_the_next_deal() {
+        let game = Game::new(Player::South);
+        let started = GameEvent::started(&game);
+        let game = game
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .record_tricks(30, 20)
+            .unwrap()
+            .start_new_hand()
+            .unwrap()
+            .undo()
+            .unwrap()
+            .undo()
+            .unwrap()
+            .record_tricks(20, 30)
+            .unwrap();
+        let events = [vec![started], game.changes().to_vec()].concat();
+
+        let tricks = Game::as_of(&events, 1, Some(GameAction::Tricks)).unwrap();
+
+        assert_eq!(tricks.current_hand().unwrap().us_tricks(), Some(20));
+        assert_eq!(tricks.running_totals(), game.running_totals());
+        assert!(matches!(Game::as_of(&events, 2, None), Err(GameError::NotReached(_))));
+    }
+}
@@ -1,11 +1,23 @@
-use crate::domain::HandError;
+use crate::domain::{HandError, HandId};
 
 #[derive(Debug, thiserror::Error)]
 pub enum GameError {
-    #[error("Invalid state transition: {0}")]
-    InvalidStateTransition(String),
     #[error("Invalid game operation: {0}")]
     InvalidOperation(String),
+    #[error("Invalid seating: {0}")]
+    InvalidSeating(String),
+    #[error("Game is over")]
+    GameOver,
+    #[error("Nothing to undo")]
+    NothingToUndo,
+    #[error("Nothing to redo")]
+    NothingToRedo,
+    #[error("Hand {0} is still being played")]
+    HandInProgress(HandId),
+    #[error("Hand not found: {0}")]
+    HandNotFound(HandId),
+    #[error("The game never reached {0}")]
+    NotReached(String),
     #[error("Hand error: {0}")]
     HandError(#[from] HandError)
 }
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use crate::domain::{Game, GameEvent};
+
+/// A change to a game that can be undone and redone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GameAction {
+    StartHand,
+    Bid,
+    AllPass,
+    Widow,
+    DeclareTrump,
+    Meld,
+    Tricks,
+    StartPlay,
+    PlayCard,
+    Substitute,
+    EditHand,
+}
+
+/// The events of each action taken on a game, newest last, with the start
+/// they build on. Undoing replays the start and every action but the last,
+/// moving that one onto the redo stack; redoing applies its events again.
+/// Any new action clears the redo stack.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GameHistory {
+    start: Option<GameEvent>,
+    undo: Vec<(GameAction, Vec<GameEvent>)>,
+    redo: Vec<(GameAction, Vec<GameEvent>)>,
+}
+
+impl GameHistory {
+    /// Remembers the `events` `action` raised on `before`. The first action
+    /// also remembers how `before` was started.
+    pub(crate) fn record(&self, action: GameAction, events: Vec<GameEvent>, before: &Game) -> Self {
+        let mut undo = self.undo.clone();
+        undo.push((action, events));
+        Self {
+            start: self.start.clone().or_else(|| Some(GameEvent::started(before))),
+            undo,
+            redo: Vec::new(),
+        }
+    }
+
+    /// The history with `completed` as the result of the last action, so a
+    /// replay keeps the time the game was stored as won.
+    pub(crate) fn with_result(&self, completed: GameEvent) -> Self {
+        let mut history = self.clone();
+        if let Some((_, events)) = history.undo.last_mut() {
+            events.retain(|event| !matches!(event, GameEvent::GameCompleted { .. }));
+            events.push(completed);
+        }
+        history
+    }
+
+    /// The action to undo, the events that rebuild the game without it and
+    /// the history once it is undone.
+    pub(crate) fn undo(&self) -> Option<(GameAction, Vec<GameEvent>, Self)> {
+        let mut history = self.clone();
+        let entry = history.undo.pop()?;
+        let action = entry.0;
+        history.redo.push(entry);
+
+        let events = history.events();
+        Some((action, events, history))
+    }
+
+    /// The events that rebuild the game as it stands: its start and the
+    /// events of every action that has not been undone. Empty until the
+    /// first action.
+    pub(crate) fn events(&self) -> Vec<GameEvent> {
+        self.start.iter().cloned()
+            .chain(self.undo.iter().flat_map(|(_, events)| events.iter().cloned()))
+            .collect()
+    }
+
+    /// The action to redo, the events it raised and the history once it is
+    /// reapplied.
+    pub(crate) fn redo(&self) -> Option<(GameAction, Vec<GameEvent>, Self)> {
+        let mut history = self.clone();
+        let (action, events) = history.redo.pop()?;
+        history.undo.push((action, events.clone()));
+        Some((action, events, history))
+    }
+
+    /// The action the next undo reverts.
+    pub fn last_action(&self) -> Option<GameAction> {
+        self.undo.last().map(|(action, _)| *action)
+    }
+
+    /// The action the next redo reapplies.
+    pub fn next_redo(&self) -> Option<GameAction> {
+        self.redo.last().map(|(action, _)| *action)
+    }
+}
@@ -3,9 +3,17 @@ use crate::domain::{GameId, Player, GameState, Hand, HandError, Suit, HandState,
 mod repository;
 mod game_error;
 mod game;
+mod history;
+mod audit;
+mod event;
+mod event_store;
 
 pub use game_error::GameError;
 
 pub use game::Game;
+pub use history::{GameAction, GameHistory};
+pub use audit::HandEdit;
+pub use event::{GameEvent, MeldEntry, TricksEntry};
+pub use event_store::{EventStore, EventStoreError};
 
 pub use repository::{GameRepository, GameRepositoryError};
\ No newline at end of file
@@ -0,0 +1,95 @@
+use crate::domain::{Call, Player, Table};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuctionCall {
+    pub player: Player,
+    pub call: Call,
+}
+
+/// Every bid and pass of a hand, in the order they were called.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Auction {
+    calls: Vec<AuctionCall>,
+}
+
+impl Auction {
+    pub fn calls(&self) -> &[AuctionCall] {
+        &self.calls
+    }
+
+    pub fn with_call(&self, player: Player, call: Call) -> Self {
+        let mut calls = self.calls.clone();
+        calls.push(AuctionCall { player, call });
+        Self { calls }
+    }
+
+    pub fn high_bid(&self) -> Option<(Player, u32)> {
+        self.calls
+            .iter()
+            .filter_map(|entry| match entry.call {
+                Call::Bid(amount) => Some((entry.player, amount)),
+                Call::Pass => None,
+            })
+            .next_back()
+    }
+
+    pub fn has_passed(&self, player: Player) -> bool {
+        self.calls
+            .iter()
+            .any(|entry| entry.player == player && entry.call == Call::Pass)
+    }
+
+    pub fn passes(&self) -> usize {
+        self.calls
+            .iter()
+            .filter(|entry| entry.call == Call::Pass)
+            .count()
+    }
+
+    /// The auction opens left of the dealer and moves clockwise around the
+    /// table, skipping anyone who has already passed. `None` when the dealer
+    /// is not seated at the table.
+    pub fn next_to_call(&self, dealer: Player, table: &Table) -> Option<Player> {
+        let mut next = match self.calls.last() {
+            Some(entry) => table.next_clockwise(entry.player)?,
+            None => return table.next_clockwise(dealer),
+        };
+
+        for _ in 0..table.seats().len() {
+            if !self.has_passed(next) {
+                break;
+            }
+            next = table.next_clockwise(next)?;
+        }
+
+        Some(next)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_open_left_of_dealer() {
+        let auction = Auction::default();
+
+        assert_eq!(auction.next_to_call(Player::South, &Table::default()), Some(Player::West));
+        assert_eq!(auction.next_to_call(Player::NorthEast, &Table::default()), None);
+    }
+
+    #[test]
+    fn should_skip_players_who_passed() {
+        let auction = Auction::default()
+            .with_call(Player::West, Call::Pass)
+            .with_call(Player::North, Call::Bid(50))
+            .with_call(Player::East, Call::Bid(51))
+            .with_call(Player::South, Call::Pass);
+
+        assert_eq!(auction.next_to_call(Player::South, &Table::default()), Some(Player::North));
+        assert_eq!(auction.high_bid(), Some((Player::East, 51)));
+        assert_eq!(auction.passes(), 2);
+        assert!(auction.has_passed(Player::West));
+        assert!(!auction.has_passed(Player::North));
+    }
+}
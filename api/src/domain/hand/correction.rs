@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+use serde::Serialize;
+use crate::domain::{Hand, Player, Suit, TeamId};
+
+/// Corrected values for a completed hand. Anything left out keeps the
+/// value the hand was scored with; meld and tricks are corrected team by
+/// team.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HandCorrection {
+    pub bidder: Option<Player>,
+    pub bid_amount: Option<u32>,
+    pub trump: Option<Suit>,
+    pub meld: BTreeMap<TeamId, u32>,
+    pub tricks: BTreeMap<TeamId, u32>,
+}
+
+/// The values a completed hand was scored with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct HandRecord {
+    pub bidder: Player,
+    pub bid_amount: u32,
+    pub trump: Suit,
+    pub meld: BTreeMap<TeamId, u32>,
+    pub tricks: BTreeMap<TeamId, u32>,
+    pub totals: BTreeMap<TeamId, i32>,
+}
+
+impl HandRecord {
+    /// The record of `hand`, once it is completed.
+    pub fn of(hand: &Hand) -> Option<Self> {
+        let (bidder, bid_amount, trump) = (hand.bidder()?, hand.bid_amount()?, hand.trump()?);
+        hand.was_set()?;
+
+        Some(Self {
+            bidder,
+            bid_amount,
+            trump,
+            meld: hand.score().meld.clone(),
+            tricks: hand.score().tricks.clone(),
+            totals: hand.score().totals.clone(),
+        })
+    }
+}
@@ -1,4 +1,5 @@
-use crate::domain::{HandError, HandId, HandState, Player, Suit, Team};
+use std::collections::{BTreeMap, HashMap};
+use crate::domain::{meld, AllPassRule, Auction, PlayState, Call, Card, Deal, Dealer, Deck, HandCorrection, HandError, HandRecord, HandId, HandScore, HandState, MeldItem, Player, PlayerProfile, RuleSet, Suit, Team, TeamId};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Hand {
@@ -7,6 +8,17 @@ pub struct Hand {
     state: HandState,
     bidder: Option<Player>,
     bid_amount: Option<u32>,
+    rule_set: RuleSet,
+    auction: Auction,
+    meld_items: HashMap<Player, Vec<MeldItem>>,
+    player_meld: HashMap<Player, u32>,
+    player_tricks: HashMap<Player, u32>,
+    play: Option<PlayState>,
+    seed: Option<u64>,
+    score: HandScore,
+    widow: Vec<Card>,
+    buried: Vec<Card>,
+    players: HashMap<Player, PlayerProfile>,
 }
 
 impl Hand {
@@ -16,10 +28,42 @@ impl Hand {
             dealer,
             state: HandState::WaitingForBid,
             bidder: None,
-            bid_amount: None
+            bid_amount: None,
+            rule_set: RuleSet::default(),
+            auction: Auction::default(),
+            meld_items: HashMap::new(),
+            player_meld: HashMap::new(),
+            player_tricks: HashMap::new(),
+            play: None,
+            seed: None,
+            score: HandScore::default(),
+            widow: Vec::new(),
+            buried: Vec::new(),
+            players: HashMap::new(),
         }
     }
 
+    pub fn with_rule_set(mut self, rule_set: RuleSet) -> Self {
+        self.rule_set = rule_set;
+        self
+    }
+
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    pub fn with_players(mut self, players: HashMap<Player, PlayerProfile>) -> Self {
+        self.players = players;
+        self
+    }
+
+    /// Gives the hand the id it had when it was first dealt.
+    pub(crate) fn with_id(mut self, id: HandId) -> Self {
+        self.id = id;
+        self
+    }
+
     pub fn id(&self) -> HandId {
         self.id
     }
@@ -32,6 +76,99 @@ impl Hand {
         self.state
     }
     
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+
+    pub fn auction(&self) -> &Auction {
+        &self.auction
+    }
+
+    /// The named melds each player laid down, when the meld was itemized.
+    pub fn meld_items(&self) -> &HashMap<Player, Vec<MeldItem>> {
+        &self.meld_items
+    }
+
+    /// Meld per seat, when it was entered by player rather than by team.
+    pub fn player_meld(&self) -> &HashMap<Player, u32> {
+        &self.player_meld
+    }
+
+    /// Trick points per seat, when they were entered by player rather than by team.
+    pub fn player_tricks(&self) -> &HashMap<Player, u32> {
+        &self.player_tricks
+    }
+
+    /// Trick-by-trick play, when the hand is being played out card by card.
+    pub fn play(&self) -> Option<&PlayState> {
+        self.play.as_ref()
+    }
+
+    /// Who sat in each seat for this hand, when the game has a roster.
+    pub fn players(&self) -> &HashMap<Player, PlayerProfile> {
+        &self.players
+    }
+
+    /// The seed the hand was shuffled with, when it was dealt by the dealer.
+    pub fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Redeals the seeded shuffle, giving exactly the cards each player was
+    /// dealt and the widow.
+    pub fn dealt_cards(&self) -> Option<Deal> {
+        self.seed.and_then(|seed| Dealer::new(seed).deal(self.rule_set.deck_kind, &self.rule_set.table, self.dealer))
+    }
+
+    /// Meld, tricks and totals of every team at the table.
+    pub fn score(&self) -> &HandScore {
+        &self.score
+    }
+
+    /// The hand total of one team, zero until the hand is completed.
+    pub fn team_total(&self, team: TeamId) -> i32 {
+        match self.state {
+            HandState::Completed { .. } => self.score.totals.get(&team).copied().unwrap_or(0),
+            _ => 0
+        }
+    }
+
+    /// Whether the bidding team went set, once the hand is completed.
+    pub fn was_set(&self) -> Option<bool> {
+        match self.state {
+            HandState::Completed { bidder, .. } => self.rule_set.table.team_of(bidder).map(|team| self.team_total(team) < 0),
+            _ => None
+        }
+    }
+
+    /// The meld `seat` laid down: as entered by player or itemized when it
+    /// was, otherwise the meld of the seat's team.
+    pub fn meld_of(&self, seat: Player) -> u32 {
+        if !self.player_meld.is_empty() {
+            return self.player_meld.get(&seat).copied().unwrap_or(0);
+        }
+        if !self.meld_items.is_empty() {
+            return self.meld_items.get(&seat).map(|items| items.iter().map(|item| item.points).sum()).unwrap_or(0);
+        }
+        self.rule_set.table.team_of(seat).and_then(|team| self.score.meld.get(&team)).copied().unwrap_or(0)
+    }
+
+    /// The widow as recorded when it was exposed, otherwise as dealt.
+    pub fn widow(&self) -> Vec<Card> {
+        if !self.widow.is_empty() {
+            return self.widow.clone();
+        }
+        self.dealt_cards().map(|deal| deal.widow).unwrap_or_default()
+    }
+
+    /// The cards the next player may legally play to the current trick.
+    pub fn legal_plays(&self) -> Vec<Card> {
+        match (self.state, &self.play) {
+            (HandState::Playing { trump, .. }, Some(play)) => play.legal_cards(play.next_to_play(), trump),
+            _ => Vec::new(),
+        }
+    }
+
     pub fn with_state(mut self, state: HandState) -> Self {
         self.state = state;
         self
@@ -43,6 +180,10 @@ impl Hand {
                 tracing::info!("Waiting for Trump, Bidder: {:?} ", bidder);
                 Some(bidder)
             },
+            HandState::WidowExposed { bidder, .. } => {
+                tracing::info!("Widow Exposed, Bidder: {:?} ", bidder);
+                Some(bidder)
+            },
             HandState::NoMarriage { bidder, .. } => {
                 tracing::info!("No Marriage,Bidder: {:?} ", bidder);
                 Some(bidder)
@@ -55,6 +196,10 @@ impl Hand {
                 tracing::info!("Waiting for Tricks, Bidder:  {:?} ", bidder);
                 Some(bidder)
             },
+            HandState::Playing { bidder, .. } => {
+                tracing::info!("Playing, Bidder:  {:?} ", bidder);
+                Some(bidder)
+            },
             HandState::Completed { bidder, .. } => {
                 tracing::info!("Completed, Bidder:  {:?} ", bidder);
                 Some(bidder)
@@ -66,9 +211,11 @@ impl Hand {
     pub fn bid_amount(&self) -> Option<u32> {
         match self.state {
             HandState::WaitingForTrump { bid_amount, .. } => Some(bid_amount),
+            HandState::WidowExposed { bid_amount, .. } => Some(bid_amount),
             HandState::NoMarriage { bid_amount, .. } => Some(bid_amount),
             HandState::WaitingForMeld { bid_amount, .. } => Some(bid_amount),
             HandState::WaitingForTricks { bid_amount, .. } => Some(bid_amount),
+            HandState::Playing { bid_amount, .. } => Some(bid_amount),
             HandState::Completed { bid_amount, .. } => Some(bid_amount),
             _ => None
         }
@@ -78,53 +225,45 @@ impl Hand {
         match self.state {
             HandState::WaitingForMeld { trump, .. } => Some(trump),
             HandState::WaitingForTricks { trump, .. } => Some(trump),
+            HandState::Playing { trump, .. } => Some(trump),
             HandState::Completed { trump, .. } => Some(trump),
             _ => None
         }
     }
 
+    /// The Us total at a two-team table, zero until the hand is completed.
     pub fn us_total(&self) -> i32 {
-        match self.state {
-            HandState::Completed { us_total, .. } => us_total.unwrap_or(0),
-            _ => 0
-        }
+        self.rule_set.table.team_id(Team::Us).map_or(0, |team| self.team_total(team))
     }
 
     pub fn them_total(&self) -> i32 {
-        match self.state {
-            HandState::Completed { them_total, .. } => them_total.unwrap_or(0),
-            _ => 0
-        }
+        self.rule_set.table.team_id(Team::Them).map_or(0, |team| self.team_total(team))
     }
-    
+
+    /// The Us meld at a two-team table, once meld has been recorded.
     pub fn us_meld(&self) -> Option<u32> {
-        match self.state {
-            HandState::Completed { us_meld, .. } => us_meld,
-            HandState::WaitingForTricks { us_meld, .. } => us_meld,
-            _ => None
-        }
+        self.has_meld().then(|| self.project(&self.score.meld).0).flatten()
     }
-    
+
     pub fn them_meld(&self) -> Option<u32> {
-        match self.state {
-            HandState::Completed { them_meld, .. } => them_meld,
-            HandState::WaitingForTricks { them_meld, .. } => them_meld,
-            _ => None
-        }
+        self.has_meld().then(|| self.project(&self.score.meld).1).flatten()
     }
 
+    /// The Us trick points at a two-team table, once the hand is completed.
     pub fn us_tricks(&self) -> Option<u32> {
-        match self.state {
-            HandState::Completed { us_tricks, .. } => us_tricks,
-            _ => None
-        }
+        self.is_completed().then(|| self.project(&self.score.tricks).0).flatten()
     }
 
     pub fn them_tricks(&self) -> Option<u32> {
-        match self.state {
-            HandState::Completed { them_tricks, .. } => them_tricks,
-            _ => None
-        }
+        self.is_completed().then(|| self.project(&self.score.tricks).1).flatten()
+    }
+
+    fn has_meld(&self) -> bool {
+        matches!(self.state, HandState::WaitingForTricks { .. } | HandState::Playing { .. } | HandState::Completed { .. })
+    }
+
+    fn is_completed(&self) -> bool {
+        matches!(self.state, HandState::Completed { .. })
     }
 
     pub fn place_bid(&self, bidder: Player, bid_amount: u32) -> Result<Self, HandError> {
@@ -132,26 +271,152 @@ impl Hand {
             return Err(HandError::InvalidStateTransition("Hand is not waiting for bid".to_string()));
         }
 
-        if !Hand::validate_bid_increment(bid_amount) {
+        if !self.validate_bid_increment(bid_amount) {
             return Err(HandError::InvalidBid(
                 format!("Invalid bid amount: {}. Must follow increment rules", bid_amount)
             ));
         }
+        self.team_of(bidder)?;
 
         Ok(Self {
             bidder: Some(bidder),
             bid_amount: Some(bid_amount),
-            state: HandState::WaitingForTrump{
-                bidder,
-                bid_amount
-            },
+            state: self.contract_state(bidder, bid_amount),
             ..self.clone()
         })
     }
 
+    /// Records the next bid or pass of the auction. The auction closes once
+    /// every player but one has passed and the remaining bidder wins the
+    /// contract.
+    pub fn record_call(&self, player: Player, call: Call) -> Result<Self, HandError> {
+        if !matches!(self.state, HandState::WaitingForBid | HandState::Bidding { .. }) {
+            return Err(HandError::InvalidStateTransition("Hand is not waiting for bid".to_string()));
+        }
+
+        if self.auction.has_passed(player) {
+            return Err(HandError::InvalidBid(format!("{} has already passed", player)));
+        }
+
+        let table = &self.rule_set.table;
+        let seats = table.seats().len();
+        let next_to_call = self.auction.next_to_call(self.dealer, table).ok_or(HandError::NotSeated(self.dealer))?;
+        if player != next_to_call {
+            return Err(HandError::InvalidBid(format!("It is {}'s turn to call", next_to_call)));
+        }
+
+        let high_bid = self.auction.high_bid();
+
+        if let Call::Bid(amount) = call {
+            if !self.validate_bid_increment(amount) {
+                return Err(HandError::InvalidBid(
+                    format!("Invalid bid amount: {}. Must follow increment rules", amount)
+                ));
+            }
+
+            if let Some((_, high_amount)) = high_bid && amount <= high_amount {
+                return Err(HandError::InvalidBid(
+                    format!("Bid of {} must beat the current bid of {}", amount, high_amount)
+                ));
+            }
+        }
+
+        let auction = self.auction.with_call(player, call);
+
+        match (auction.high_bid(), self.rule_set.all_pass) {
+            (None, AllPassRule::DealerStuck) if auction.passes() == seats - 1 => {
+                return Ok(Self {
+                    auction,
+                    ..self.clone()
+                }.settle_all_pass());
+            },
+            (None, AllPassRule::Redeal) if auction.passes() == seats => {
+                return Ok(Self {
+                    auction,
+                    ..self.clone()
+                }.settle_all_pass());
+            },
+            _ => {}
+        }
+
+        match auction.high_bid() {
+            Some((bidder, bid_amount)) if auction.passes() == seats - 1 => Ok(Self {
+                bidder: Some(bidder),
+                bid_amount: Some(bid_amount),
+                state: self.contract_state(bidder, bid_amount),
+                auction,
+                ..self.clone()
+            }),
+            high_bid => Ok(Self {
+                state: HandState::Bidding {
+                    next_to_call: auction.next_to_call(self.dealer, table).ok_or(HandError::NotSeated(self.dealer))?,
+                    high_bidder: high_bid.map(|(bidder, _)| bidder),
+                    high_bid: high_bid.map(|(_, amount)| amount),
+                },
+                auction,
+                ..self.clone()
+            })
+        }
+    }
+
+    /// Records that nobody bid on this hand. Depending on the rule set the
+    /// dealer is stuck with the minimum bid or the hand is thrown in.
+    pub fn all_pass(&self) -> Result<Self, HandError> {
+        if self.state != HandState::WaitingForBid {
+            return Err(HandError::InvalidStateTransition("Hand is not waiting for bid".to_string()));
+        }
+
+        let table = &self.rule_set.table;
+        let first = table.next_clockwise(self.dealer).ok_or(HandError::NotSeated(self.dealer))?;
+        let auction = std::iter::successors(Some(first), |player| table.next_clockwise(*player))
+            .take(table.seats().len() - 1)
+            .fold(self.auction.clone(), |auction, player| auction.with_call(player, Call::Pass));
+
+        let auction = match self.rule_set.all_pass {
+            AllPassRule::DealerStuck => auction,
+            AllPassRule::Redeal => auction.with_call(self.dealer, Call::Pass),
+        };
+
+        Ok(Self {
+            auction,
+            ..self.clone()
+        }.settle_all_pass())
+    }
+
+    fn settle_all_pass(self) -> Self {
+        match self.rule_set.all_pass {
+            AllPassRule::DealerStuck => {
+                let bidder = self.dealer;
+                let bid_amount = self.rule_set.minimum_bid;
+                tracing::info!("Everyone passed, {:?} is stuck at {}", bidder, bid_amount);
+                Self {
+                    bidder: Some(bidder),
+                    bid_amount: Some(bid_amount),
+                    state: self.contract_state(bidder, bid_amount),
+                    ..self
+                }
+            },
+            AllPassRule::Redeal => Self {
+                state: HandState::Redealt,
+                ..self
+            }
+        }
+    }
+
+    /// Where the hand goes once the contract is settled: the widow is turned
+    /// up first at tables that deal one.
+    fn contract_state(&self, bidder: Player, bid_amount: u32) -> HandState {
+        if self.rule_set.table.widow() > 0 {
+            HandState::WidowExposed { bidder, bid_amount }
+        } else {
+            HandState::WaitingForTrump { bidder, bid_amount }
+        }
+    }
+
     pub fn declare_trump(&self, trump: Suit) -> Result<Self, HandError> {
         match self.state {
-            HandState::WaitingForTrump { bidder: current_bidder, bid_amount } => {
+            HandState::WaitingForTrump { bidder: current_bidder, bid_amount }
+            | HandState::WidowExposed { bidder: current_bidder, bid_amount } => {
                 if trump != Suit::NoMarriage {
                     Ok(Self {
                         state: HandState::WaitingForMeld {
@@ -175,225 +440,453 @@ impl Hand {
         }
     }
 
+    /// Records the cards turned up in the widow before the bidder takes it.
+    pub fn record_widow(self, widow: Vec<Card>) -> Result<Self, HandError> {
+        if !matches!(self.state, HandState::WidowExposed { .. }) {
+            return Err(HandError::InvalidStateTransition("Widow is not exposed".to_string()));
+        }
+
+        if widow.len() != self.rule_set.table.widow() {
+            return Err(HandError::InvalidWidow(format!(
+                "Widow holds {} cards, not {}", self.rule_set.table.widow(), widow.len()
+            )));
+        }
+
+        Ok(Self {
+            widow,
+            ..self
+        })
+    }
+
+    /// Records Us/Them meld totals at a two-team table.
     pub fn record_meld(self, us: u32, them: u32) -> Result<Self, HandError> {
-        let us_meld = Hand::validate_points(us);
-        let them_meld = Hand::validate_points(them);
+        let meld = self.two_teams(us, them)?;
+        self.record_team_meld(meld)
+    }
+
+    /// Records the meld of every team at the table. Meld below the rule
+    /// set's minimum does not count, and a bidding team without meld is set
+    /// without playing for tricks.
+    pub fn record_team_meld(self, entered: BTreeMap<TeamId, u32>) -> Result<Self, HandError> {
+        let table = &self.rule_set.table;
+        let meld: BTreeMap<TeamId, u32> = table.team_ids()
+            .filter_map(|team| self.validate_points(entered.get(&team).copied().unwrap_or(0)).map(|points| (team, points)))
+            .collect();
 
         match self.state {
-            HandState::WaitingForMeld { bidder: current_bidder, bid_amount, trump } => {
-                let bidding_team = current_bidder.team();
-                let bidding_team_meld = Self::team_meld(bidding_team, us_meld, them_meld);
-
-                if bidding_team_meld.is_none() {
-                    let (us_score, them_score) = Self::failed_meld_scores(bidding_team, bid_amount, us_meld, them_meld);
-                    return Ok(Self {
-                        state: HandState::Completed {
-                            bidder: current_bidder,
-                            bid_amount,
-                            trump,
-                            us_meld,
-                            them_meld,
-                            us_tricks: None,
-                            them_tricks: None,
-                            us_total: Some(us_score),
-                            them_total: Some(them_score),
-                        },
-                        ..self.clone()
-                    });
+            HandState::WaitingForMeld { bidder, bid_amount, trump } => {
+                let bidding_team = self.team_of(bidder)?;
+                let score = HandScore { meld, ..HandScore::default() };
+
+                if !score.meld.contains_key(&bidding_team) {
+                    return Ok(self.set_without_tricks(bidder, bid_amount, trump, score));
                 }
 
                 Ok(Self {
                     state: HandState::WaitingForTricks {
-                        bidder: current_bidder,
+                        bidder,
                         bid_amount,
                         trump,
-                        us_meld,
-                        them_meld,
                     },
-                    ..self.clone()
+                    score,
+                    ..self
                 })
             },
-            HandState::NoMarriage { bidder: current_bidder, bid_amount } => {
-                Ok(Self {
-                    state: HandState::Completed {
-                        bidder: current_bidder,
-                        bid_amount,
-                        trump: Suit::NoMarriage,
-                        us_meld: if current_bidder.team() == Team::Us { None } else { us_meld },
-                        them_meld: if current_bidder.team() == Team::Them { None } else { them_meld },
-                        us_tricks: None,
-                        them_tricks: None,
-                        us_total: Some(-(bid_amount as i32)),
-                        them_total: Some(them_meld.unwrap_or(0) as i32),
-                    },
-                    ..self.clone()
-                })
+            HandState::NoMarriage { bidder, bid_amount } => {
+                let mut meld = meld;
+                meld.remove(&self.team_of(bidder)?);
+                let score = HandScore { meld, ..HandScore::default() };
+
+                Ok(self.set_without_tricks(bidder, bid_amount, Suit::NoMarriage, score))
             },
             _ => Err(HandError::InvalidStateTransition("Hand is not waiting for meld".to_string())),
         }
     }
 
-    fn team_meld(team: Team, us_meld: Option<u32>, them_meld: Option<u32>) -> Option<u32> {
-        match team {
-            Team::Us => us_meld,
-            Team::Them => them_meld,
+    /// Completes a hand whose bidding team was set before tricks: the bidding
+    /// team loses its bid and every other team keeps its meld.
+    fn set_without_tricks(self, bidder: Player, bid_amount: u32, trump: Suit, score: HandScore) -> Self {
+        let bidding_team = self.rule_set.table.team_of(bidder);
+        let totals = self.rule_set.table.team_ids()
+            .map(|team| match Some(team) == bidding_team {
+                true => (team, -(bid_amount as i32)),
+                false => (team, score.meld.get(&team).copied().unwrap_or(0) as i32),
+            })
+            .collect();
+        let score = HandScore { totals, ..score };
+
+        Self {
+            state: HandState::Completed { bidder, bid_amount, trump },
+            score,
+            ..self
+        }
+    }
+
+    /// Records team totals together with the named melds behind them. Each
+    /// team's items must add up to the total entered for that team.
+    pub fn record_meld_itemized(self, us: u32, them: u32, items: HashMap<Player, Vec<MeldItem>>) -> Result<Self, HandError> {
+        let meld = self.two_teams(us, them)?;
+        self.record_team_meld_itemized(meld, items)
+    }
+
+    fn record_team_meld_itemized(self, meld: BTreeMap<TeamId, u32>, items: HashMap<Player, Vec<MeldItem>>) -> Result<Self, HandError> {
+        let player_meld: HashMap<Player, u32> = items.iter()
+            .map(|(player, player_items)| (*player, player_items.iter().map(|item| item.points).sum()))
+            .collect();
+        let itemized_totals = self.team_totals(&player_meld)?;
+
+        for team in self.rule_set.table.team_ids() {
+            let total = meld.get(&team).copied().unwrap_or(0);
+            let itemized = itemized_totals.get(&team).copied().unwrap_or(0);
+
+            if itemized != total {
+                return Err(HandError::InvalidMeld(format!("{} melds add up to {} but {} was entered", self.rule_set.table.team_name(team).unwrap_or_default(), itemized, total)));
+            }
         }
+
+        let hand = self.record_team_meld(meld)?;
+        Ok(Self {
+            meld_items: items,
+            player_meld,
+            ..hand
+        })
+    }
+
+    /// Records meld entered per seat, deriving the team totals from each
+    /// player's team.
+    pub fn record_meld_by_player(self, by_player: HashMap<Player, u32>) -> Result<Self, HandError> {
+        let meld = self.team_totals(&by_player)?;
+
+        let hand = self.record_team_meld(meld)?;
+        Ok(Self {
+            player_meld: by_player,
+            ..hand
+        })
+    }
+
+    /// Records trick points taken per seat, deriving the team totals from
+    /// each player's team.
+    pub fn record_tricks_by_player(self, by_player: HashMap<Player, u32>) -> Result<Self, HandError> {
+        let tricks = self.team_totals(&by_player)?;
+
+        let hand = self.record_team_tricks(tricks)?;
+        Ok(Self {
+            player_tricks: by_player,
+            ..hand
+        })
     }
 
-    fn failed_meld_scores(team: Team, bid_amount: u32, us_meld: Option<u32>, them_meld: Option<u32>) -> (i32, i32) {
-        match team {
-            Team::Us => (-(bid_amount as i32), them_meld.unwrap_or(0) as i32),
-            Team::Them => (us_meld.unwrap_or(0) as i32, -(bid_amount as i32)),
+    fn team_totals(&self, by_player: &HashMap<Player, u32>) -> Result<BTreeMap<TeamId, u32>, HandError> {
+        let mut totals = BTreeMap::new();
+        for (player, points) in by_player {
+            *totals.entry(self.team_of(*player)?).or_default() += points;
         }
+        Ok(totals)
     }
 
-    pub fn record_tricks(self, us: u32, them: u32) -> Result<Self, HandError> {
-        tracing::info!("Validating tricks");
+    /// The team `player` plays for, rejecting players not seated at the table.
+    fn team_of(&self, player: Player) -> Result<TeamId, HandError> {
+        self.rule_set.table.team_of(player).ok_or(HandError::NotSeated(player))
+    }
+
+    /// Scores each player's laid-down cards with the meld calculator and
+    /// records the team totals along with the calculated breakdown.
+    pub fn record_meld_cards(self, cards: &HashMap<Player, Vec<Card>>) -> Result<Self, HandError> {
+        let trump = match self.state {
+            HandState::WaitingForMeld { trump, .. } => trump,
+            HandState::NoMarriage { .. } => Suit::NoMarriage,
+            _ => return Err(HandError::InvalidStateTransition("Hand is not waiting for meld".to_string())),
+        };
 
-        // If both are zero, fail
-        if us == 0 && them == 0 {
-            return Err(HandError::InvalidTricks(us, them));
+        let mut meld = BTreeMap::new();
+        let mut items = HashMap::new();
+        for (player, player_cards) in cards {
+            let breakdown = meld::calculate(player_cards, trump, self.rule_set.deck_kind);
+            tracing::info!("{:?} melds {} from {:?}", player, breakdown.total(), breakdown.items);
+            *meld.entry(self.team_of(*player)?).or_default() += breakdown.total();
+            items.insert(*player, breakdown.items);
         }
 
-        let mut final_us = us;
-        let mut final_them = them;
+        self.record_team_meld_itemized(meld, items)
+    }
+
+    /// Starts trick-by-trick play with the cards each player holds. The
+    /// bidder leads the first trick.
+    pub fn start_play(self, hands: HashMap<Player, Vec<Card>>) -> Result<Self, HandError> {
+        let HandState::WaitingForTricks { bidder, bid_amount, trump } = self.state else {
+            return Err(HandError::InvalidStateTransition("Hand is not waiting for tricks".to_string()));
+        };
 
-        // If either is zero, infer the other
-        if us == 0 {
-            final_us = 50 - them;
+        let table = self.rule_set.table.clone();
+        if table.seats().iter().any(|seat| hands.get(seat).map(Vec::len) != hands.get(&bidder).map(Vec::len)) {
+            return Err(HandError::IllegalPlay("Every player must hold the same number of cards".to_string()));
         }
-        if them == 0 {
-            final_them = 50 - us;
+        let buried = self.undealt_cards(&hands)?;
+        let widow = if self.widow.is_empty() { buried.clone() } else { self.widow.clone() };
+
+        Ok(Self {
+            state: HandState::Playing { bidder, bid_amount, trump },
+            play: Some(PlayState::new(hands, bidder, table)),
+            widow,
+            buried,
+            ..self
+        })
+    }
+
+    /// Checks the hands against the rule set's deck and returns the cards
+    /// left out of them: the widow, or whatever the bidder buried from the
+    /// widow and their own hand.
+    fn undealt_cards(&self, hands: &HashMap<Player, Vec<Card>>) -> Result<Vec<Card>, HandError> {
+        let deck = Deck::new(self.rule_set.deck_kind);
+        let counters = deck.cards().iter().filter(|card| card.is_counter()).count() as u32;
+        if counters != self.rule_set.counters() {
+            return Err(HandError::InvalidCounters(counters, self.rule_set.counters()));
         }
 
-        // Must sum to 50
-        if final_us + final_them != 50 {
-            return Err(HandError::InvalidTricks(final_us, final_them));
+        let mut remaining = card_counts(deck.cards());
+        for card in hands.values().flatten() {
+            match remaining.get_mut(card) {
+                Some(count) if *count > 0 => *count -= 1,
+                _ => return Err(HandError::IllegalPlay(format!("{} is dealt more often than it is in the deck", card))),
+            }
         }
 
-        tracing::info!("Validated tricks {0} {1} {2}", final_us, final_them, 50);
-        tracing::info!("Validating hand state: {:?}", self.state);
-        let HandState::WaitingForTricks { bidder, bid_amount, trump, us_meld, them_meld } = self.state else {
-            return Err(HandError::InvalidStateTransition("Hand is not waiting for tricks".to_string()));
+        let mut undealt = Vec::new();
+        for card in deck.cards() {
+            if let Some(count) = remaining.get_mut(card) && *count > 0 {
+                *count -= 1;
+                undealt.push(*card);
+            }
+        }
+        if undealt.len() != self.rule_set.table.widow() {
+            return Err(HandError::IllegalPlay(format!("{} cards of the deck were not dealt", undealt.len())));
+        }
+
+        Ok(undealt)
+    }
+
+    /// Plays one card to the current trick. Once the last trick is taken the
+    /// counters are scored with `record_team_counters`.
+    pub fn play_card(self, player: Player, card: Card) -> Result<Self, HandError> {
+        let (HandState::Playing { bidder, bid_amount, trump }, Some(play)) = (self.state, &self.play) else {
+            return Err(HandError::InvalidStateTransition("Hand is not being played".to_string()));
+        };
+
+        let play = play.play(player, card, trump).map_err(HandError::IllegalPlay)?;
+
+        if !play.is_finished() {
+            return Ok(Self {
+                play: Some(play),
+                ..self
+            });
+        }
+
+        // The bidder takes the widow and buries as many cards, so the
+        // counters buried are the bidder's.
+        let bidder_team = self.team_of(bidder)?;
+        let (mut counters, last_trick) = play.counters();
+        *counters.entry(bidder_team).or_default() += self.buried.iter().filter(|card| card.is_counter()).count() as u32;
+        let last_trick = last_trick.unwrap_or(bidder_team);
+        let played = Self {
+            state: HandState::WaitingForTricks { bidder, bid_amount, trump },
+            play: Some(play),
+            ..self
         };
-        tracing::info!("Validated hand state");
-        tracing::info!("Identifying bidding team");
-        let bidding_team = bidder.team();
-        tracing::info!("Calculating required tricks");
-        let required_tricks = Self::required_tricks(bid_amount, us_meld, them_meld, bidding_team);
-        tracing::info!("Identifying tricks for bidding team");
-        let bidder_tricks = match bidding_team {
-            Team::Us => final_us,
-            Team::Them => final_them
+
+        // Leave the hand waiting for tricks rather than stuck in play when the
+        // counters taken cannot be scored.
+        match played.clone().record_team_counters(counters, last_trick) {
+            Err(HandError::InvalidCounters(first, rest)) => {
+                tracing::warn!("Counters taken in play do not add up ({} + {}), waiting for tricks", first, rest);
+                Ok(played)
+            },
+            result => result,
+        }
+    }
+
+    /// Records tricks from the counters each team took, adding the last
+    /// trick bonus for the team that took the last trick.
+    pub fn record_counters(self, us: u32, them: u32, last_trick: Team) -> Result<Self, HandError> {
+        let counters = self.two_teams(us, them)?;
+        let last_trick = self.rule_set.table.team_id(last_trick).unwrap_or(last_trick.into());
+        self.record_team_counters(counters, last_trick)
+    }
+
+    pub fn record_team_counters(self, counters: BTreeMap<TeamId, u32>, last_trick: TeamId) -> Result<Self, HandError> {
+        if counters.values().sum::<u32>() != self.rule_set.counters() {
+            let (first, rest) = self.split_first(&counters);
+            return Err(HandError::InvalidCounters(first, rest));
+        }
+
+        let mut tricks = counters;
+        *tricks.entry(last_trick).or_default() += self.rule_set.last_trick_bonus;
+        self.record_team_tricks(tricks)
+    }
+
+    /// Records Us/Them trick points at a two-team table.
+    pub fn record_tricks(self, us: u32, them: u32) -> Result<Self, HandError> {
+        let tricks = self.two_teams(us, them)?;
+        self.record_team_tricks(tricks)
+    }
+
+    /// Records the trick points of every team and scores the hand. When
+    /// exactly one team is left at zero it is given the points the others
+    /// did not take.
+    pub fn record_team_tricks(self, entered: BTreeMap<TeamId, u32>) -> Result<Self, HandError> {
+        tracing::info!("Validating tricks");
+        let table = self.rule_set.table.clone();
+        let trick_points = self.rule_set.trick_points;
+        let mut tricks: BTreeMap<TeamId, u32> = table.team_ids()
+            .map(|team| (team, entered.get(&team).copied().unwrap_or(0)))
+            .collect();
+
+        let entered_total: u32 = tricks.values().sum();
+        if entered_total == 0 {
+            return Err(HandError::InvalidTricks(0, 0));
+        }
+
+        let missing: Vec<TeamId> = tricks.iter().filter(|(_, points)| **points == 0).map(|(team, _)| *team).collect();
+        if entered_total != trick_points && let [team] = missing.as_slice() {
+            tricks.insert(*team, trick_points.saturating_sub(entered_total));
+        }
+
+        // Must sum to the rule set's trick points
+        if tricks.values().sum::<u32>() != trick_points {
+            let (first, rest) = self.split_first(&tricks);
+            return Err(HandError::InvalidTricks(first, rest));
+        }
+
+        tracing::info!("Validated tricks {:?} {}", tricks, trick_points);
+        let HandState::WaitingForTricks { bidder, bid_amount, trump, .. } = self.state else {
+            return Err(HandError::InvalidStateTransition("Hand is not waiting for tricks".to_string()));
         };
-        tracing::info!("Calculating team totals");
-        let us_total = Self::calculate_team_total(us_meld.unwrap_or(0), final_us);
-        let them_total = Self::calculate_team_total(them_meld.unwrap_or(0), final_them);
-        tracing::info!("Applying bidding penalty");
-        let (us_total, them_total) = Self::apply_bidding_penalty(
-            bidding_team,
-            us_total,
-            them_total,
-            bid_amount,
-            bidder_tricks,
-            required_tricks);
-        tracing::info!("Normalizing melds");
-        let us_meld = Self::normalize_meld(us_meld);
-        let them_meld = Self::normalize_meld(them_meld);
-        tracing::info!("Normalizing tricks");
-        let us_tricks = Some(final_us);
-        let them_tricks = Some(final_them);
-        tracing::info!("Normalizing totals");
-        let us_total = Self::normalize_total(us_total);
-        let them_total = Self::normalize_total(them_total);
+
+        let bidding_team = self.team_of(bidder)?;
+        let meld = self.score.meld.clone();
+        let required_tricks = self.required_tricks(bid_amount, meld.get(&bidding_team).copied());
+        let totals = table.team_ids()
+            .map(|team| {
+                let taken = tricks[&team];
+                let total = if team == bidding_team && taken < required_tricks {
+                    -(bid_amount as i32)
+                } else {
+                    self.calculate_team_total(meld.get(&team).copied().unwrap_or(0), taken)
+                };
+                (team, total)
+            })
+            .collect();
+
+        let score = HandScore { meld, tricks, totals };
         tracing::info!("Returning completed hand");
         Ok(Self {
-            state: HandState::Completed {
-                bidder,
-                bid_amount,
-                trump,
-                us_meld,
-                them_meld,
-                us_tricks,
-                them_tricks,
-                us_total,
-                them_total
-            },
-            ..self.clone()
+            state: HandState::Completed { bidder, bid_amount, trump },
+            score,
+            ..self
         })
     }
-    
+
+    /// Scores a completed hand again from corrected values, running the
+    /// bid, trump, meld and tricks through the same rules as when it was
+    /// played. The auction, and meld or tricks entered by player or card, are
+    /// dropped when the contract or the team totals they add up to change.
+    pub fn rescore(&self, correction: &HandCorrection) -> Result<Self, HandError> {
+        let record = HandRecord::of(self)
+            .ok_or_else(|| HandError::InvalidStateTransition("Only a completed hand can be corrected".to_string()))?;
+
+        let mut meld = record.meld.clone();
+        meld.extend(&correction.meld);
+        let mut tricks = record.tricks.clone();
+        tricks.extend(&correction.tricks);
+
+        let bidder = correction.bidder.unwrap_or(record.bidder);
+        let bid_amount = correction.bid_amount.unwrap_or(record.bid_amount);
+        let contract_changed = (bidder, bid_amount) != (record.bidder, record.bid_amount);
+        let meld_changed = meld != record.meld;
+        let tricks_changed = tricks != record.tricks;
+
+        let hand = Self {
+            state: HandState::WaitingForBid,
+            bidder: None,
+            bid_amount: None,
+            score: HandScore::default(),
+            auction: if contract_changed { Auction::default() } else { self.auction.clone() },
+            meld_items: if meld_changed { HashMap::new() } else { self.meld_items.clone() },
+            player_meld: if meld_changed { HashMap::new() } else { self.player_meld.clone() },
+            player_tricks: if tricks_changed { HashMap::new() } else { self.player_tricks.clone() },
+            play: if tricks_changed { None } else { self.play.clone() },
+            ..self.clone()
+        }
+            .place_bid(bidder, bid_amount)?
+            .declare_trump(correction.trump.unwrap_or(record.trump))?
+            .record_team_meld(meld)?;
+
+        match hand.state {
+            HandState::WaitingForTricks { .. } => hand.record_team_tricks(tricks),
+            _ => Ok(hand),
+        }
+    }
+
     pub fn tricks_to_save(&self) -> Option<u32> {
         match self.state {
-            HandState::WaitingForTricks {bidder,bid_amount, us_meld, them_meld, ..}
-            | HandState::Completed{bidder,bid_amount, us_meld, them_meld, ..} => Some(Hand::required_tricks(bid_amount, us_meld, them_meld, bidder.team())),
+            HandState::WaitingForTricks { bidder, bid_amount, .. }
+            | HandState::Playing { bidder, bid_amount, .. }
+            | HandState::Completed { bidder, bid_amount, .. } => {
+                let bidding_team = self.rule_set.table.team_of(bidder)?;
+                Some(self.required_tricks(bid_amount, self.score.meld.get(&bidding_team).copied()))
+            },
             _ => None
         }
     }
 
-    fn required_tricks(bid_amount: u32, us_meld: Option<u32>, them_meld: Option<u32>, bidding_team: Team) -> u32 {
-        match bidding_team {
-            Team::Us => (std::cmp::max((bid_amount as i32) - (us_meld.unwrap_or(0) as i32), 20)) as u32,
-            Team::Them => (std::cmp::max((bid_amount as i32) - (them_meld.unwrap_or(0) as i32), 20)) as u32
-        } 
+    fn required_tricks(&self, bid_amount: u32, bidding_meld: Option<u32>) -> u32 {
+        let minimum_tricks = self.rule_set.minimum_tricks as i32;
+        std::cmp::max((bid_amount as i32) - (bidding_meld.unwrap_or(0) as i32), minimum_tricks) as u32
     }
 
-    fn normalize_meld(meld: Option<u32>) ->  Option<u32> {
-        match meld {
-            Some(m) if m < 20 => None,
-            other => other,
+    fn project<T: Copy>(&self, by_team: &BTreeMap<TeamId, T>) -> (Option<T>, Option<T>) {
+        match self.rule_set.table.us_them() {
+            Some((us, them)) => (by_team.get(&us).copied(), by_team.get(&them).copied()),
+            None => (None, None),
         }
     }
-    
-    fn normalize_total(total: i32) -> Option<i32 >{
-        if total == 0 { None } else { Some(total)}
+
+    fn two_teams(&self, us: u32, them: u32) -> Result<BTreeMap<TeamId, u32>, HandError> {
+        let (us_team, them_team) = self.rule_set.table.us_them()
+            .ok_or_else(|| HandError::UnsupportedTable("Us/Them totals need a two-team table; enter them by player".to_string()))?;
+        Ok(BTreeMap::from([(us_team, us), (them_team, them)]))
+    }
+
+    /// Splits team points for an error message: Us and Them at a two-team
+    /// table, otherwise the first team against the rest.
+    fn split_first(&self, by_team: &BTreeMap<TeamId, u32>) -> (u32, u32) {
+        if let (Some(us), Some(them)) = self.project(by_team) {
+            return (us, them);
+        }
+        let mut values = by_team.values();
+        let first = values.next().copied().unwrap_or(0);
+        (first, values.sum())
     }
 
-    fn calculate_team_total(meld: u32, tricks: u32) -> i32 {
-        if tricks < 20 {
+    fn calculate_team_total(&self, meld: u32, tricks: u32) -> i32 {
+        if tricks < self.rule_set.minimum_tricks {
             0
-        } else if meld < 20 {
+        } else if meld < self.rule_set.minimum_meld {
             tricks as i32
         } else {
             (meld + tricks) as i32
         }
     }
-    
-    fn apply_bidding_penalty(
-        bidding_team: Team, 
-        us_total: i32, them_total: i32, 
-        bid_amount: u32, bidding_tricks: u32, 
-        required_tricks: u32) -> (i32, i32) 
-    {
-        if bidding_tricks < required_tricks {
-            match bidding_team {
-                Team::Us => (-(bid_amount as i32), them_total),
-                Team::Them => (us_total, -(bid_amount as i32))
-            }
-        } else {
-            (us_total, them_total)
-        }
-    }
-
 
-    fn validate_points(meld: u32) -> Option<u32> {
-        if meld < 20 {
+    fn validate_points(&self, meld: u32) -> Option<u32> {
+        if meld < self.rule_set.minimum_meld {
             None
         } else {
             Some(meld)
         }
     }
 
-    fn validate_bid_increment(amount: u32) -> bool {
-        if amount < 50 {
-            return false;
-        } else if amount < 60 {
-            return true;
-        } else if amount < 100 {
-            return amount % 5 == 0;
-        } else {
-            return amount % 10 == 0;
-        }
+    fn validate_bid_increment(&self, amount: u32) -> bool {
+        self.rule_set.is_valid_bid(amount)
     }
 }
 
@@ -401,22 +894,33 @@ impl Hand {
 #[cfg(test)]
 
 impl Hand {
-    pub fn set_state(&self, state: HandState) -> Self {
+    pub fn set_state(&self, state: HandState, score: HandScore) -> Self {
         Self {
             state,
+            score,
             ..self.clone()
         }
     }
 
     pub fn get_validate_points(meld: u32) -> Option<u32> {
-        Hand::validate_points(meld)
+        Hand::new(Player::South).validate_points(meld)
     }
 }
-mod tests {
-    use super::*;
 
-    #[test]
-    fn new_hand_should_start_waiting_for_bid() {
+fn card_counts(cards: &[Card]) -> HashMap<Card, usize> {
+    let mut counts = HashMap::new();
+    for card in cards {
+        *counts.entry(*card).or_default() += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_hand_should_start_waiting_for_bid() {
         let hand = Hand::new(Player::South);
 
         assert_eq!(hand.dealer, Player::South);
@@ -631,12 +1135,12 @@ mod tests {
         let new_hand = result.unwrap();
 
         match new_hand.state() {
-            HandState::WaitingForTricks {bidder, bid_amount, trump, us_meld, them_meld} => {
+            HandState::WaitingForTricks { bidder, bid_amount, trump } => {
                 assert_eq!(bidder, Player::North);
                 assert_eq!(bid_amount, 51);
                 assert_eq!(trump, Suit::Spades);
-                assert_eq!(us_meld, Some(24));
-                assert_eq!(them_meld, Some(32));
+                assert_eq!(new_hand.us_meld(), Some(24));
+                assert_eq!(new_hand.them_meld(), Some(32));
             },
             _ => panic!("Expected WaitingForTricks state")
         }
@@ -657,16 +1161,16 @@ mod tests {
         let new_hand = result.unwrap();
 
         match new_hand.state() {
-            HandState::Completed {bidder, bid_amount, trump, us_meld, them_meld, us_tricks, them_tricks, us_total, them_total} => {
+            HandState::Completed { bidder, bid_amount, trump } => {
                 assert_eq!(bidder, Player::North);
                 assert_eq!(bid_amount, 51);
                 assert_eq!(trump, Suit::Spades);
-                assert_eq!(us_meld, None);
-                assert_eq!(them_meld, Some(32));
-                assert_eq!(us_tricks, None);
-                assert_eq!(them_tricks, None);
-                assert_eq!(us_total, Some(-51));
-                assert_eq!(them_total, Some(32));
+                assert_eq!(new_hand.us_meld(), None);
+                assert_eq!(new_hand.them_meld(), Some(32));
+                assert_eq!(new_hand.us_tricks(), None);
+                assert_eq!(new_hand.them_tricks(), None);
+                assert_eq!(new_hand.us_total(), -51);
+                assert_eq!(new_hand.them_total(), 32);
             },
             _ => panic!("Expected WaitingForTricks state")
         }
@@ -687,16 +1191,16 @@ mod tests {
         let new_hand = result.unwrap();
 
         match new_hand.state() {
-            HandState::Completed {bidder, bid_amount, trump, us_meld, them_meld, us_tricks, them_tricks, us_total, them_total} => {
+            HandState::Completed { bidder, bid_amount, trump } => {
                 assert_eq!(bidder, Player::North);
                 assert_eq!(bid_amount, 51);
                 assert_eq!(trump, Suit::Spades);
-                assert_eq!(us_meld, None);
-                assert_eq!(them_meld, Some(32));
-                assert_eq!(us_tricks, None);
-                assert_eq!(them_tricks, None);
-                assert_eq!(us_total, Some(-51));
-                assert_eq!(them_total, Some(32));
+                assert_eq!(new_hand.us_meld(), None);
+                assert_eq!(new_hand.them_meld(), Some(32));
+                assert_eq!(new_hand.us_tricks(), None);
+                assert_eq!(new_hand.them_tricks(), None);
+                assert_eq!(new_hand.us_total(), -51);
+                assert_eq!(new_hand.them_total(), 32);
             },
             _ => panic!("Expected Complete state")
         }
@@ -717,16 +1221,16 @@ mod tests {
         let new_hand = result.unwrap();
 
         match new_hand.state() {
-            HandState::Completed {bidder, bid_amount, trump, us_meld, them_meld, us_tricks, them_tricks, us_total, them_total} => {
+            HandState::Completed { bidder, bid_amount, trump } => {
                 assert_eq!(bidder, Player::North);
                 assert_eq!(bid_amount, 51);
                 assert_eq!(trump, Suit::NoMarriage);
-                assert_eq!(us_meld, None);
-                assert_eq!(them_meld, Some(32));
-                assert_eq!(us_tricks, None);
-                assert_eq!(them_tricks, None);
-                assert_eq!(us_total, Some(-51));
-                assert_eq!(them_total, Some(32));
+                assert_eq!(new_hand.us_meld(), None);
+                assert_eq!(new_hand.them_meld(), Some(32));
+                assert_eq!(new_hand.us_tricks(), None);
+                assert_eq!(new_hand.them_tricks(), None);
+                assert_eq!(new_hand.us_total(), -51);
+                assert_eq!(new_hand.them_total(), 32);
             },
             _ => panic!("Expected Complete state")
         }
@@ -765,16 +1269,16 @@ mod tests {
         let new_hand = result.unwrap();
 
         match new_hand.state() {
-            HandState::Completed {bidder, bid_amount, trump, us_meld, them_meld, us_tricks, them_tricks, us_total, them_total} => {
+            HandState::Completed { bidder, bid_amount, trump } => {
                 assert_eq!(bidder, Player::North);
                 assert_eq!(bid_amount, 51);
                 assert_eq!(trump, Suit::Spades);
-                assert_eq!(us_meld, Some(24));
-                assert_eq!(them_meld, Some(32));
-                assert_eq!(us_tricks, Some(27));
-                assert_eq!(them_tricks, Some(23));
-                assert_eq!(us_total, Some(51));
-                assert_eq!(them_total, Some(55));
+                assert_eq!(new_hand.us_meld(), Some(24));
+                assert_eq!(new_hand.them_meld(), Some(32));
+                assert_eq!(new_hand.us_tricks(), Some(27));
+                assert_eq!(new_hand.them_tricks(), Some(23));
+                assert_eq!(new_hand.us_total(), 51);
+                assert_eq!(new_hand.them_total(), 55);
             },
             _ => panic!("Expected Complete state")
         }
@@ -811,11 +1315,11 @@ mod tests {
         let new_hand = result.unwrap();
 
         match new_hand.state() {
-            HandState::Completed {us_tricks, them_tricks, us_total, them_total, ..} => {
-                assert_eq!(us_tricks, Some(36));
-                assert_eq!(them_tricks, Some(14));
-                assert_eq!(us_total, Some(60));
-                assert_eq!(them_total, None);
+            HandState::Completed { .. } => {
+                assert_eq!(new_hand.us_tricks(), Some(36));
+                assert_eq!(new_hand.them_tricks(), Some(14));
+                assert_eq!(new_hand.us_total(), 60);
+                assert_eq!(new_hand.them_total(), 0);
             },
             _ => panic!("Expected Completed state")
         }
@@ -837,11 +1341,11 @@ mod tests {
         let new_hand = result.unwrap();
 
         match new_hand.state() {
-            HandState::Completed {us_tricks, them_tricks, us_total, them_total, ..} => {
-                assert_eq!(us_tricks, Some(30));
-                assert_eq!(them_tricks, Some(20));
-                assert_eq!(us_total, Some(-60i32));
-                assert_eq!(them_total, Some(52i32));
+            HandState::Completed { .. } => {
+                assert_eq!(new_hand.us_tricks(), Some(30));
+                assert_eq!(new_hand.them_tricks(), Some(20));
+                assert_eq!(new_hand.us_total(), -60);
+                assert_eq!(new_hand.them_total(), 52);
             },
             _ => panic!("Expected Complete state")
         }
@@ -907,17 +1411,17 @@ mod tests {
         let new_hand = result.unwrap();
 
         match new_hand.state() {
-            HandState::Completed {bidder, bid_amount, trump, us_meld, them_meld, us_tricks, them_tricks, us_total, them_total} => {
+            HandState::Completed { bidder, bid_amount, trump } => {
                 assert_eq!(bidder, Player::North);
                 assert_eq!(bid_amount, 51);
                 assert_eq!(trump, Suit::Spades);
-                assert_eq!(us_meld, None, "us_meld should be None for zero");
-                assert_eq!(them_meld, None, "them_meld should be None for zero");
-                assert_eq!(us_tricks, None);
-                assert_eq!(them_tricks, None);
+                assert_eq!(new_hand.us_meld(), None, "new_hand.us_meld() should be None for zero");
+                assert_eq!(new_hand.them_meld(), None, "new_hand.them_meld() should be None for zero");
+                assert_eq!(new_hand.us_tricks(), None);
+                assert_eq!(new_hand.them_tricks(), None);
                 // Forfeiting meld means negative bid for bidding team, zero for other
-                assert_eq!(us_total, Some(-51));
-                assert_eq!(them_total, Some(0));
+                assert_eq!(new_hand.us_total(), -51);
+                assert_eq!(new_hand.them_total(), 0);
             },
             _ => panic!("Expected Completed state")
         }
@@ -935,16 +1439,16 @@ mod tests {
         let new_hand = result.unwrap();
 
         match new_hand.state() {
-            HandState::Completed {bidder, bid_amount, trump, us_meld, them_meld, us_tricks, them_tricks, us_total, them_total} => {
+            HandState::Completed { bidder, bid_amount, trump } => {
                 assert_eq!(bidder, Player::North);
                 assert_eq!(bid_amount, 51);
                 assert_eq!(trump, Suit::Spades);
-                assert_eq!(us_meld, None);
-                assert_eq!(them_meld, Some(24));
-                assert_eq!(us_tricks, None);
-                assert_eq!(them_tricks, None);
-                assert_eq!(us_total, Some(-51));
-                assert_eq!(them_total, Some(24));
+                assert_eq!(new_hand.us_meld(), None);
+                assert_eq!(new_hand.them_meld(), Some(24));
+                assert_eq!(new_hand.us_tricks(), None);
+                assert_eq!(new_hand.them_tricks(), None);
+                assert_eq!(new_hand.us_total(), -51);
+                assert_eq!(new_hand.them_total(), 24);
             },
             _ => panic!("Expected Completed state")
         }
@@ -962,18 +1466,663 @@ mod tests {
         let new_hand = result.unwrap();
 
         match new_hand.state() {
-            HandState::Completed {bidder, bid_amount, trump, us_meld, them_meld, us_tricks, them_tricks, us_total, them_total} => {
+            HandState::Completed { bidder, bid_amount, trump } => {
                 assert_eq!(bidder, Player::North);
                 assert_eq!(bid_amount, 51);
                 assert_eq!(trump, Suit::Spades);
-                assert_eq!(us_meld, None);
-                assert_eq!(them_meld, Some(24));
-                assert_eq!(us_tricks, None);
-                assert_eq!(them_tricks, None);
-                assert_eq!(us_total, Some(-51));
-                assert_eq!(them_total, Some(24));
+                assert_eq!(new_hand.us_meld(), None);
+                assert_eq!(new_hand.them_meld(), Some(24));
+                assert_eq!(new_hand.us_tricks(), None);
+                assert_eq!(new_hand.them_tricks(), None);
+                assert_eq!(new_hand.us_total(), -51);
+                assert_eq!(new_hand.them_total(), 24);
             },
             _ => panic!("Expected Completed state")
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn should_validate_bids_against_rule_set() {
+        let hand = Hand::new(Player::South).with_rule_set(RuleSet::single_deck_150());
+
+        assert!(hand.place_bid(Player::North, 21).is_ok());
+        assert!(hand.place_bid(Player::North, 19).is_err());
+    }
+
+    #[test]
+    fn should_score_tricks_with_rule_set_trick_points() {
+        let hand = Hand::new(Player::South)
+            .with_rule_set(RuleSet::single_deck_150())
+            .place_bid(Player::North, 20)
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap()
+            .record_meld(8, 4)
+            .unwrap();
+
+        assert!(hand.clone().record_tricks(14, 14).is_err());
+
+        let hand = hand.record_tricks(15, 0).unwrap();
+
+        assert_eq!(hand.us_tricks(), Some(15));
+        assert_eq!(hand.them_tricks(), Some(10));
+        assert_eq!(hand.us_total(), 23);
+        assert_eq!(hand.them_total(), 14);
+    }
+
+    #[test]
+    fn should_apply_rule_set_minimum_tricks_to_required_tricks() {
+        let hand = Hand::new(Player::South)
+            .with_rule_set(RuleSet::single_deck_150())
+            .place_bid(Player::North, 20)
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap()
+            .record_meld(30, 4)
+            .unwrap();
+
+        assert_eq!(hand.tricks_to_save(), Some(1));
+    }
+
+    #[test]
+    fn should_record_auction_in_seat_order_until_three_pass() {
+        let hand = Hand::new(Player::South)
+            .record_call(Player::West, Call::Bid(50))
+            .unwrap()
+            .record_call(Player::North, Call::Bid(51))
+            .unwrap()
+            .record_call(Player::East, Call::Pass)
+            .unwrap();
+
+        match hand.state() {
+            HandState::Bidding { next_to_call, high_bidder, high_bid } => {
+                assert_eq!(next_to_call, Player::South);
+                assert_eq!(high_bidder, Some(Player::North));
+                assert_eq!(high_bid, Some(51));
+            },
+            _ => panic!("Expected Bidding state")
+        }
+
+        let hand = hand
+            .record_call(Player::South, Call::Pass)
+            .unwrap()
+            .record_call(Player::West, Call::Pass)
+            .unwrap();
+
+        assert_eq!(hand.state(), HandState::WaitingForTrump { bidder: Player::North, bid_amount: 51 });
+        assert_eq!(hand.auction().calls().len(), 5);
+    }
+
+    #[test]
+    fn should_reject_call_out_of_turn() {
+        let hand = Hand::new(Player::South);
+
+        let result = hand.record_call(Player::North, Call::Bid(50));
+
+        assert!(matches!(result, Err(HandError::InvalidBid(_))));
+    }
+
+    #[test]
+    fn should_reject_bid_that_does_not_beat_high_bid() {
+        let hand = Hand::new(Player::South)
+            .record_call(Player::West, Call::Bid(55))
+            .unwrap();
+
+        assert!(hand.record_call(Player::North, Call::Bid(55)).is_err());
+        assert!(hand.record_call(Player::North, Call::Bid(61)).is_err());
+        assert!(hand.record_call(Player::North, Call::Bid(60)).is_ok());
+    }
+
+    #[test]
+    fn should_not_allow_reentry_after_pass() {
+        let hand = Hand::new(Player::South)
+            .record_call(Player::West, Call::Pass)
+            .unwrap()
+            .record_call(Player::North, Call::Bid(50))
+            .unwrap()
+            .record_call(Player::East, Call::Bid(51))
+            .unwrap()
+            .record_call(Player::South, Call::Bid(52))
+            .unwrap();
+
+        let result = hand.record_call(Player::West, Call::Bid(55));
+
+        assert!(matches!(result, Err(HandError::InvalidBid(_))));
+        assert_eq!(hand.auction().next_to_call(hand.dealer(), &hand.rule_set().table), Some(Player::North));
+    }
+
+    #[test]
+    fn should_let_last_player_bid_when_others_pass() {
+        let redeal = RuleSet { all_pass: AllPassRule::Redeal, ..RuleSet::default() };
+        let hand = Hand::new(Player::South)
+            .with_rule_set(redeal)
+            .record_call(Player::West, Call::Pass)
+            .unwrap()
+            .record_call(Player::North, Call::Pass)
+            .unwrap()
+            .record_call(Player::East, Call::Pass)
+            .unwrap();
+
+        let hand = hand.record_call(Player::South, Call::Bid(50)).unwrap();
+        assert_eq!(hand.bidder(), Some(Player::South));
+        assert_eq!(hand.bid_amount(), Some(50));
+    }
+
+    #[test]
+    fn should_stick_dealer_with_minimum_bid_when_three_pass() {
+        let hand = Hand::new(Player::South)
+            .record_call(Player::West, Call::Pass)
+            .unwrap()
+            .record_call(Player::North, Call::Pass)
+            .unwrap()
+            .record_call(Player::East, Call::Pass)
+            .unwrap();
+
+        assert_eq!(hand.state(), HandState::WaitingForTrump { bidder: Player::South, bid_amount: 50 });
+    }
+
+    #[test]
+    fn should_redeal_when_all_four_pass_under_redeal_rule() {
+        let redeal = RuleSet { all_pass: AllPassRule::Redeal, ..RuleSet::default() };
+        let hand = Hand::new(Player::South)
+            .with_rule_set(redeal)
+            .record_call(Player::West, Call::Pass)
+            .unwrap()
+            .record_call(Player::North, Call::Pass)
+            .unwrap()
+            .record_call(Player::East, Call::Pass)
+            .unwrap()
+            .record_call(Player::South, Call::Pass)
+            .unwrap();
+
+        assert_eq!(hand.state(), HandState::Redealt);
+        assert_eq!(hand.bidder(), None);
+    }
+
+    #[test]
+    fn all_pass_should_stick_dealer_by_default() {
+        let hand = Hand::new(Player::East).all_pass().unwrap();
+
+        assert_eq!(hand.bidder(), Some(Player::East));
+        assert_eq!(hand.bid_amount(), Some(50));
+        assert_eq!(hand.auction().passes(), 3);
+    }
+
+    #[test]
+    fn all_pass_should_throw_in_hand_under_redeal_rule() {
+        let redeal = RuleSet { all_pass: AllPassRule::Redeal, ..RuleSet::default() };
+        let hand = Hand::new(Player::East).with_rule_set(redeal).all_pass().unwrap();
+
+        assert_eq!(hand.state(), HandState::Redealt);
+        assert_eq!(hand.auction().passes(), 4);
+    }
+
+    #[test]
+    fn all_pass_should_only_be_allowed_before_bidding() {
+        let hand = Hand::new(Player::South)
+            .record_call(Player::West, Call::Bid(50))
+            .unwrap();
+
+        assert!(hand.all_pass().is_err());
+    }
+
+    #[test]
+    fn should_record_meld_calculated_from_cards() {
+        use crate::domain::Rank;
+
+        let hand = Hand::new(Player::South)
+            .place_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap();
+        let run: Vec<Card> = [Rank::Ace, Rank::Ten, Rank::King, Rank::Queen, Rank::Jack]
+            .into_iter()
+            .map(|rank| Card::new(rank, Suit::Hearts))
+            .collect();
+        let aces: Vec<Card> = Suit::PLAYABLE.into_iter().map(|suit| Card::new(Rank::Ace, suit)).collect();
+        let pinochle = vec![Card::new(Rank::Queen, Suit::Spades), Card::new(Rank::Jack, Suit::Diamonds)];
+
+        let cards = HashMap::from([
+            (Player::North, run),
+            (Player::South, aces),
+            (Player::East, pinochle),
+        ]);
+
+        let hand = hand.record_meld_cards(&cards).unwrap();
+
+        assert_eq!(hand.us_meld(), Some(25));
+        assert_eq!(hand.them_meld(), None);
+        assert!(matches!(hand.state(), HandState::WaitingForTricks { .. }));
+        assert_eq!(hand.meld_items()[&Player::North][0].name, "Run in Hearts");
+        assert_eq!(hand.meld_items()[&Player::East][0].name, "Pinochle");
+    }
+
+    fn item(name: &str, points: u32) -> MeldItem {
+        MeldItem { name: name.to_string(), points }
+    }
+
+    #[test]
+    fn should_keep_itemized_meld_with_team_totals() {
+        let hand = Hand::new(Player::South)
+            .place_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap();
+        let items = HashMap::from([
+            (Player::North, vec![item("Run in Hearts", 15), item("Marriage in Spades", 2)]),
+            (Player::South, vec![item("Pinochle", 4)]),
+            (Player::West, vec![item("Double Pinochle", 30)]),
+        ]);
+
+        let hand = hand.record_meld_itemized(21, 30, items.clone()).unwrap();
+
+        assert_eq!(hand.us_meld(), Some(21));
+        assert_eq!(hand.them_meld(), Some(30));
+        assert_eq!(hand.meld_items(), &items);
+    }
+
+    #[test]
+    fn should_reject_itemized_meld_that_does_not_match_team_total() {
+        let hand = Hand::new(Player::South)
+            .place_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap();
+        let items = HashMap::from([(Player::North, vec![item("Run in Hearts", 15)])]);
+
+        let result = hand.record_meld_itemized(25, 0, items);
+
+        assert!(matches!(result, Err(HandError::InvalidMeld(_))));
+    }
+
+    #[test]
+    fn should_derive_team_totals_from_player_meld_and_tricks() {
+        let hand = Hand::new(Player::South)
+            .place_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap();
+        let meld = HashMap::from([
+            (Player::North, 15),
+            (Player::South, 10),
+            (Player::East, 20),
+            (Player::West, 4),
+        ]);
+        let tricks = HashMap::from([
+            (Player::North, 18),
+            (Player::South, 12),
+            (Player::East, 14),
+            (Player::West, 6),
+        ]);
+
+        let hand = hand.record_meld_by_player(meld.clone()).unwrap();
+        assert_eq!(hand.us_meld(), Some(25));
+        assert_eq!(hand.them_meld(), Some(24));
+
+        let hand = hand.record_tricks_by_player(tricks.clone()).unwrap();
+        assert_eq!(hand.us_tricks(), Some(30));
+        assert_eq!(hand.them_tricks(), Some(20));
+        assert_eq!(hand.player_meld(), &meld);
+        assert_eq!(hand.player_tricks(), &tricks);
+    }
+
+    #[test]
+    fn should_convert_counters_and_last_trick_into_points() {
+        let hand = Hand::new(Player::South)
+            .place_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap();
+
+        let hand = hand.record_counters(30, 18, Team::Them).unwrap();
+
+        assert_eq!(hand.us_tricks(), Some(30));
+        assert_eq!(hand.them_tricks(), Some(20));
+    }
+
+    #[test]
+    fn should_reject_counters_that_do_not_match_deck() {
+        let hand = Hand::new(Player::South)
+            .place_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap();
+
+        let result = hand.record_counters(30, 20, Team::Us);
+
+        assert!(matches!(result, Err(HandError::InvalidCounters(30, 20))));
+    }
+
+    fn single_deck_deal() -> HashMap<Player, Vec<Card>> {
+        use crate::domain::Rank;
+
+        let deck: Vec<Card> = [Rank::Nine, Rank::Jack, Rank::Queen, Rank::King, Rank::Ten, Rank::Ace]
+            .into_iter()
+            .flat_map(|rank| Suit::PLAYABLE.into_iter().map(move |suit| Card::new(rank, suit)))
+            .flat_map(|card| [card, card])
+            .collect();
+
+        let seats = [Player::West, Player::North, Player::East, Player::South];
+        let mut hands: HashMap<Player, Vec<Card>> = HashMap::new();
+        for (index, card) in deck.into_iter().enumerate() {
+            hands.entry(seats[index % seats.len()]).or_default().push(card);
+        }
+        hands
+    }
+
+    fn playing_hand_waiting_for_tricks() -> Hand {
+        Hand::new(Player::South)
+            .with_rule_set(RuleSet::single_deck_150())
+            .place_bid(Player::North, 20)
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap()
+            .record_meld(20, 0)
+            .unwrap()
+    }
+
+    fn playing_hand() -> Hand {
+        playing_hand_waiting_for_tricks()
+            .start_play(single_deck_deal())
+            .unwrap()
+    }
+
+    #[test]
+    fn should_score_counters_once_every_card_is_played() {
+        let mut hand = playing_hand();
+        assert!(matches!(hand.state(), HandState::Playing { .. }));
+
+        while let Some(player) = hand.play().filter(|play| !play.is_finished()).map(|play| play.next_to_play()) {
+            let card = hand.legal_plays()[0];
+            hand = hand.play_card(player, card).unwrap();
+        }
+
+        assert!(matches!(hand.state(), HandState::Completed { .. }));
+        assert_eq!(hand.us_tricks().unwrap() + hand.them_tricks().unwrap(), 25);
+        assert_eq!(hand.play().unwrap().tricks_played(), 12);
+    }
+
+    #[test]
+    fn should_lead_with_bidder_and_reject_illegal_play() {
+        let hand = playing_hand();
+        assert_eq!(hand.play().unwrap().next_to_play(), Player::North);
+
+        let result = hand.play_card(Player::East, Card::new(crate::domain::Rank::Ace, Suit::Spades));
+
+        assert!(matches!(result, Err(HandError::IllegalPlay(_))));
+    }
+
+    #[test]
+    fn should_reject_play_with_cards_that_do_not_make_up_the_deck() {
+        let mut doubled = single_deck_deal();
+        let ace = doubled[&Player::North][0];
+        doubled.get_mut(&Player::East).unwrap()[0] = ace;
+        doubled.get_mut(&Player::West).unwrap()[0] = ace;
+        assert!(matches!(playing_hand_waiting_for_tricks().start_play(doubled), Err(HandError::IllegalPlay(_))));
+
+        let mut short = single_deck_deal();
+        short.values_mut().for_each(|cards| { cards.pop(); });
+        assert!(matches!(playing_hand_waiting_for_tricks().start_play(short), Err(HandError::IllegalPlay(_))));
+    }
+
+    #[test]
+    fn should_only_start_play_when_waiting_for_tricks() {
+        let hand = Hand::new(Player::South);
+
+        assert!(hand.start_play(single_deck_deal()).is_err());
+    }
+
+    #[test]
+    fn should_derive_player_meld_from_itemized_meld() {
+        let hand = Hand::new(Player::South)
+            .place_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap();
+        let items = HashMap::from([
+            (Player::North, vec![item("Run in Hearts", 15), item("Pinochle", 4)]),
+            (Player::South, vec![item("Marriage in Clubs", 2)]),
+        ]);
+
+        let hand = hand.record_meld_itemized(21, 0, items).unwrap();
+
+        assert_eq!(hand.player_meld(), &HashMap::from([(Player::North, 19), (Player::South, 2)]));
+    }
+
+    #[test]
+    fn should_reject_meld_cards_before_trump() {
+        let hand = Hand::new(Player::South);
+
+        assert!(hand.record_meld_cards(&HashMap::new()).is_err());
+    }
+
+    fn cutthroat_hand() -> Hand {
+        Hand::new(Player::South)
+            .with_rule_set(RuleSet::single_deck_cutthroat())
+            .record_call(Player::North, Call::Bid(25))
+            .unwrap()
+            .record_call(Player::East, Call::Pass)
+            .unwrap()
+            .record_call(Player::South, Call::Pass)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_close_cutthroat_auction_after_two_pass_and_expose_widow() {
+        let hand = cutthroat_hand();
+
+        assert_eq!(hand.state(), HandState::WidowExposed { bidder: Player::North, bid_amount: 25 });
+        assert_eq!(hand.auction().calls().len(), 3);
+    }
+
+    #[test]
+    fn should_record_widow_before_trump() {
+        let widow = vec![
+            Card::new(crate::domain::Rank::Ace, Suit::Spades),
+            Card::new(crate::domain::Rank::Ten, Suit::Spades),
+            Card::new(crate::domain::Rank::King, Suit::Spades),
+        ];
+
+        assert!(matches!(cutthroat_hand().record_widow(widow[..2].to_vec()), Err(HandError::InvalidWidow(_))));
+
+        let hand = cutthroat_hand()
+            .record_widow(widow.clone())
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap();
+
+        assert_eq!(hand.widow(), widow);
+        assert_eq!(hand.state(), HandState::WaitingForMeld { bidder: Player::North, bid_amount: 25, trump: Suit::Spades });
+    }
+
+    #[test]
+    fn should_score_each_cutthroat_player_for_themselves() {
+        let hand = cutthroat_hand()
+            .declare_trump(Suit::Hearts)
+            .unwrap()
+            .record_meld_by_player(HashMap::from([(Player::North, 10), (Player::East, 4)]))
+            .unwrap()
+            .record_tricks_by_player(HashMap::from([(Player::North, 16), (Player::East, 6), (Player::South, 3)]))
+            .unwrap();
+
+        assert_eq!(hand.score().totals, BTreeMap::from([(TeamId(0), 26), (TeamId(1), 10), (TeamId(2), 3)]));
+        assert_eq!(hand.us_total(), 0);
+        assert_eq!(hand.us_meld(), None);
+    }
+
+    #[test]
+    fn should_set_cutthroat_bidder_short_of_contract() {
+        let hand = cutthroat_hand()
+            .declare_trump(Suit::Hearts)
+            .unwrap()
+            .record_meld_by_player(HashMap::from([(Player::North, 4)]))
+            .unwrap()
+            .record_tricks_by_player(HashMap::from([(Player::North, 10), (Player::East, 12)]))
+            .unwrap();
+
+        assert_eq!(hand.score().tricks[&TeamId(2)], 3);
+        assert_eq!(hand.team_total(TeamId(0)), -25);
+        assert_eq!(hand.team_total(TeamId(1)), 12);
+    }
+
+    #[test]
+    fn should_credit_widow_counters_to_cutthroat_bidder_when_played_out() {
+        let rule_set = RuleSet::single_deck_cutthroat();
+        let deal = Dealer::new(7).deal(rule_set.deck_kind, &rule_set.table, Player::South).unwrap();
+        let widow_counters = deal.widow.iter().filter(|card| card.is_counter()).count() as u32;
+
+        let mut hand = cutthroat_hand()
+            .declare_trump(Suit::Hearts)
+            .unwrap()
+            .record_meld_by_player(HashMap::from([(Player::North, 10)]))
+            .unwrap()
+            .start_play(deal.hands)
+            .unwrap();
+        assert_eq!(card_counts(&hand.widow()), card_counts(&deal.widow));
+
+        while let Some(player) = hand.play().filter(|play| !play.is_finished()).map(|play| play.next_to_play()) {
+            let card = hand.legal_plays()[0];
+            hand = hand.play_card(player, card).unwrap();
+        }
+
+        let (taken, _) = hand.play().unwrap().counters();
+        assert!(matches!(hand.state(), HandState::Completed { .. }));
+        assert_eq!(hand.score().tricks.values().sum::<u32>(), 25);
+        assert!(hand.score().tricks[&TeamId(0)] >= taken[&TeamId(0)] + widow_counters);
+    }
+
+    #[test]
+    fn should_credit_cards_the_cutthroat_bidder_buried_from_their_own_hand() {
+        let rule_set = RuleSet::single_deck_cutthroat();
+        let mut deal = Dealer::new(7).deal(rule_set.deck_kind, &rule_set.table, Player::South).unwrap();
+        let north = deal.hands.get_mut(&Player::North).unwrap();
+        let kept = deal.widow.iter().position(|card| !card.is_counter()).unwrap_or(0);
+        let discarded = north.iter().position(|card| card.is_counter() && !deal.widow.contains(card)).unwrap();
+        let discard = std::mem::replace(&mut north[discarded], deal.widow[kept]);
+        let mut buried = deal.widow.clone();
+        buried[kept] = discard;
+
+        let mut hand = cutthroat_hand()
+            .record_widow(deal.widow.clone())
+            .unwrap()
+            .declare_trump(Suit::Hearts)
+            .unwrap()
+            .record_meld_by_player(HashMap::from([(Player::North, 10)]))
+            .unwrap()
+            .start_play(deal.hands)
+            .unwrap();
+        assert_eq!(card_counts(&hand.buried), card_counts(&buried));
+        assert_eq!(hand.widow(), deal.widow);
+
+        while let Some(player) = hand.play().filter(|play| !play.is_finished()).map(|play| play.next_to_play()) {
+            let card = hand.legal_plays()[0];
+            hand = hand.play_card(player, card).unwrap();
+        }
+
+        let (taken, last_trick) = hand.play().unwrap().counters();
+        let bonus = if last_trick == Some(TeamId(0)) { rule_set.last_trick_bonus } else { 0 };
+        let buried_counters = buried.iter().filter(|card| card.is_counter()).count() as u32;
+        assert!(matches!(hand.state(), HandState::Completed { .. }));
+        assert_eq!(hand.score().tricks[&TeamId(0)], taken[&TeamId(0)] + buried_counters + bonus);
+    }
+
+    #[test]
+    fn should_reject_players_not_seated_at_the_table() {
+        let hand = cutthroat_hand().declare_trump(Suit::Hearts).unwrap();
+
+        assert!(matches!(hand.clone().record_meld_by_player(HashMap::from([(Player::West, 10)])), Err(HandError::NotSeated(Player::West))));
+        assert!(matches!(hand.record_meld_cards(&HashMap::from([(Player::West, vec![])])), Err(HandError::NotSeated(Player::West))));
+
+        let four_handed = Hand::new(Player::South).place_bid(Player::North, 51).unwrap().declare_trump(Suit::Hearts).unwrap();
+        let items = HashMap::from([(Player::North, vec![item("Pinochle", 4)]), (Player::NorthEast, vec![item("Run in Hearts", 15)])]);
+        assert!(matches!(four_handed.record_meld_itemized(4, 0, items), Err(HandError::NotSeated(Player::NorthEast))));
+
+        let waiting = cutthroat_hand()
+            .declare_trump(Suit::Hearts)
+            .unwrap()
+            .record_meld_by_player(HashMap::from([(Player::North, 10)]))
+            .unwrap();
+        assert!(matches!(waiting.record_tricks_by_player(HashMap::from([(Player::North, 20), (Player::West, 5)])), Err(HandError::NotSeated(Player::West))));
+        assert!(matches!(Hand::new(Player::South).place_bid(Player::NorthEast, 50), Err(HandError::NotSeated(Player::NorthEast))));
+    }
+
+    #[test]
+    fn should_require_player_entry_at_cutthroat_table() {
+        let hand = cutthroat_hand().declare_trump(Suit::Hearts).unwrap();
+
+        assert!(matches!(hand.record_meld(10, 4), Err(HandError::UnsupportedTable(_))));
+    }
+
+    #[test]
+    fn should_score_six_handed_game_with_three_teams() {
+        let hand = Hand::new(Player::North)
+            .with_rule_set(RuleSet::triple_deck_six_handed(3))
+            .record_call(Player::NorthEast, Call::Bid(80))
+            .unwrap();
+        let hand = [Player::SouthEast, Player::South, Player::SouthWest, Player::NorthWest, Player::North]
+            .into_iter()
+            .fold(hand, |hand, player| hand.record_call(player, Call::Pass).unwrap())
+            .declare_trump(Suit::Clubs)
+            .unwrap()
+            .record_meld_by_player(HashMap::from([(Player::NorthEast, 30), (Player::SouthWest, 20), (Player::North, 40)]))
+            .unwrap()
+            .record_tricks_by_player(HashMap::from([(Player::NorthEast, 20), (Player::SouthWest, 20), (Player::North, 34)]))
+            .unwrap();
+
+        assert_eq!(hand.bidder(), Some(Player::NorthEast));
+        assert_eq!(hand.score().totals, BTreeMap::from([(TeamId(0), 74), (TeamId(1), 90), (TeamId(2), 0)]));
+        assert_eq!(hand.us_total(), 0);
+    }
+
+    fn scored_hand() -> Hand {
+        Hand::new(Player::South)
+            .place_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap()
+            .record_meld(24, 32)
+            .unwrap()
+            .record_tricks(27, 23)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_rescore_completed_hand_with_corrected_tricks() {
+        let hand = scored_hand();
+
+        let corrected = hand.rescore(&HandCorrection {
+            tricks: BTreeMap::from([(TeamId::US, 20), (TeamId::THEM, 30)]),
+            ..HandCorrection::default()
+        }).unwrap();
+
+        assert_eq!(corrected.id(), hand.id());
+        assert_eq!(corrected.score().totals, BTreeMap::from([(TeamId::US, -51), (TeamId::THEM, 62)]));
+        assert_eq!(corrected.trump(), Some(Suit::Spades));
+    }
+
+    #[test]
+    fn should_set_bidder_without_tricks_when_corrected_meld_is_too_low() {
+        let corrected = scored_hand().rescore(&HandCorrection {
+            meld: BTreeMap::from([(TeamId::US, 10)]),
+            ..HandCorrection::default()
+        }).unwrap();
+
+        assert_eq!(corrected.score().totals, BTreeMap::from([(TeamId::US, -51), (TeamId::THEM, 32)]));
+        assert!(corrected.score().tricks.is_empty());
+    }
+
+    #[test]
+    fn should_reject_correcting_a_hand_in_progress() {
+        let hand = Hand::new(Player::South).place_bid(Player::North, 51).unwrap();
+
+        assert!(matches!(hand.rescore(&HandCorrection::default()), Err(HandError::InvalidStateTransition(_))));
+        assert!(matches!(
+            scored_hand().rescore(&HandCorrection { bid_amount: Some(40), ..HandCorrection::default() }),
+            Err(HandError::InvalidBid(_))
+        ));
+    }
+}
@@ -1,3 +1,5 @@
+use crate::domain::Player;
+
 #[derive(Debug, thiserror::Error)]
 pub enum HandError {
     #[error("Invalid state transition: {0}")]
@@ -6,6 +8,24 @@ pub enum HandError {
     #[error("Invalid bid: {0}")]
     InvalidBid(String),
 
-    #[error("Total tricks must not exceed 50: {0} + {1}")]
-    InvalidTricks(u32, u32)
+    #[error("Invalid meld: {0}")]
+    InvalidMeld(String),
+
+    #[error("Counters must add up to the counters in the deck: {0} + {1}")]
+    InvalidCounters(u32, u32),
+
+    #[error("Illegal play: {0}")]
+    IllegalPlay(String),
+
+    #[error("Total tricks must add up to the rule set's trick points: {0} + {1}")]
+    InvalidTricks(u32, u32),
+
+    #[error("Invalid widow: {0}")]
+    InvalidWidow(String),
+
+    #[error("Unsupported table: {0}")]
+    UnsupportedTable(String),
+
+    #[error("{0} is not seated at this table")]
+    NotSeated(Player)
 }
@@ -1,7 +1,15 @@
 pub use hand_error::HandError;
 pub use hand::Hand;
+pub use auction::{Auction, AuctionCall};
+pub use play::{PlayState, PlayedCard};
+pub use score::HandScore;
+pub use correction::{HandCorrection, HandRecord};
 pub use repository::{HandRepository, HandRepositoryError};
 
 pub mod hand_error;
 pub mod repository;
-pub mod hand;
\ No newline at end of file
+pub mod hand;
+pub mod auction;
+pub mod play;
+pub mod score;
+pub mod correction;
\ No newline at end of file
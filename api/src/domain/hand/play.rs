@@ -0,0 +1,280 @@
+use std::collections::{BTreeMap, HashMap};
+use serde::Serialize;
+use crate::domain::{Card, Player, Suit, Table, TeamId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PlayedCard {
+    pub player: Player,
+    pub card: Card,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trick {
+    leader: Player,
+    cards: Vec<PlayedCard>,
+}
+
+impl Trick {
+    fn new(leader: Player) -> Self {
+        Self { leader, cards: Vec::new() }
+    }
+
+    pub fn cards(&self) -> &[PlayedCard] {
+        &self.cards
+    }
+
+    fn led_suit(&self) -> Option<Suit> {
+        self.cards.first().map(|played| played.card.suit)
+    }
+
+    /// The card currently winning the trick: the highest trump if any was
+    /// played, otherwise the highest card of the led suit. Of two identical
+    /// cards the first one played wins.
+    fn winning(&self, trump: Suit) -> Option<PlayedCard> {
+        let led = self.led_suit()?;
+        self.cards.iter().copied().fold(None, |best: Option<PlayedCard>, played| match best {
+            None => Some(played),
+            Some(best) if Self::beats(played.card, best.card, led, trump) => Some(played),
+            best => best,
+        })
+    }
+
+    fn beats(card: Card, best: Card, led: Suit, trump: Suit) -> bool {
+        if card.suit == best.suit {
+            return card.rank > best.rank;
+        }
+        card.suit == trump || (best.suit != trump && card.suit == led)
+    }
+}
+
+/// Cards held and played during trick-by-trick play. The bidder leads the
+/// first trick and the winner of each trick leads the next.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlayState {
+    hands: HashMap<Player, Vec<Card>>,
+    completed: Vec<(Trick, Player)>,
+    current: Trick,
+    table: Table,
+}
+
+impl PlayState {
+    pub fn new(hands: HashMap<Player, Vec<Card>>, leader: Player, table: Table) -> Self {
+        Self {
+            hands,
+            completed: Vec::new(),
+            current: Trick::new(leader),
+            table,
+        }
+    }
+
+    pub fn current_trick(&self) -> &Trick {
+        &self.current
+    }
+
+    pub fn tricks_played(&self) -> usize {
+        self.completed.len()
+    }
+
+    /// The seat after the last card played to the current trick. Leaders are
+    /// always seated: the bidder leads first and trick winners after.
+    pub fn next_to_play(&self) -> Player {
+        let seats = self.table.seats();
+        let leader = seats.iter().position(|seat| *seat == self.current.leader).unwrap_or_default();
+        seats[(leader + self.current.cards.len()) % seats.len()]
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.current.cards.is_empty() && self.hands.values().all(|cards| cards.is_empty())
+    }
+
+    /// The cards `player` may legally play to the current trick.
+    pub fn legal_cards(&self, player: Player, trump: Suit) -> Vec<Card> {
+        let held = self.hands.get(&player).cloned().unwrap_or_default();
+        held.iter()
+            .copied()
+            .filter(|card| self.check_legal(&held, *card, trump).is_ok())
+            .collect()
+    }
+
+    /// Plays a card, returning why the play is illegal when it breaks the
+    /// follow-suit, head-the-trick or trump-when-void rules.
+    pub fn play(&self, player: Player, card: Card, trump: Suit) -> Result<Self, String> {
+        if self.is_finished() {
+            return Err("All tricks have been played".to_string());
+        }
+
+        let next = self.next_to_play();
+        if player != next {
+            return Err(format!("It is {}'s turn to play", next));
+        }
+
+        let held = self.hands.get(&player).cloned().unwrap_or_default();
+        let Some(position) = held.iter().position(|held_card| *held_card == card) else {
+            return Err(format!("{} does not hold the {}", player, card));
+        };
+
+        self.check_legal(&held, card, trump)?;
+
+        let mut hands = self.hands.clone();
+        let mut remaining = held;
+        remaining.remove(position);
+        hands.insert(player, remaining);
+
+        let mut current = self.current.clone();
+        current.cards.push(PlayedCard { player, card });
+
+        let mut completed = self.completed.clone();
+        if current.cards.len() == hands.len() {
+            let winner = current.winning(trump).map(|played| played.player).unwrap_or(current.leader);
+            completed.push((current, winner));
+            current = Trick::new(winner);
+        }
+
+        Ok(Self { hands, completed, current, table: self.table.clone() })
+    }
+
+    fn check_legal(&self, held: &[Card], card: Card, trump: Suit) -> Result<(), String> {
+        let (Some(led), Some(winning)) = (self.current.led_suit(), self.current.winning(trump)) else {
+            return Ok(());
+        };
+
+        let can_beat = |suit: Suit| held.iter().any(|held_card| {
+            held_card.suit == suit && Trick::beats(*held_card, winning.card, led, trump)
+        });
+
+        if held.iter().any(|held_card| held_card.suit == led) {
+            if card.suit != led {
+                return Err(format!("Must follow {}", led));
+            }
+            let trumped = winning.card.suit == trump && led != trump;
+            if !trumped && can_beat(led) && !Trick::beats(card, winning.card, led, trump) {
+                return Err("Must head the trick when able".to_string());
+            }
+            return Ok(());
+        }
+
+        if held.iter().any(|held_card| held_card.suit == trump) {
+            if card.suit != trump {
+                return Err(format!("Must trump with {} when void in {}", trump, led));
+            }
+            if can_beat(trump) && !Trick::beats(card, winning.card, led, trump) {
+                return Err("Must head the trick when able".to_string());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Counters (aces, tens and kings) taken by each team, and the team that
+    /// took the last trick.
+    pub fn counters(&self) -> (BTreeMap<TeamId, u32>, Option<TeamId>) {
+        let mut counters: BTreeMap<TeamId, u32> = self.table.team_ids().map(|team| (team, 0)).collect();
+        for (trick, winner) in &self.completed {
+            let taken = trick.cards.iter()
+                .filter(|played| played.card.is_counter())
+                .count() as u32;
+            if let Some(team) = self.table.team_of(*winner) {
+                *counters.entry(team).or_default() += taken;
+            }
+        }
+
+        (counters, self.completed.last().and_then(|(_, winner)| self.table.team_of(*winner)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Rank;
+
+    fn card(rank: Rank, suit: Suit) -> Card {
+        Card::new(rank, suit)
+    }
+
+    fn state(hands: [(Player, Vec<Card>); 4]) -> PlayState {
+        PlayState::new(HashMap::from(hands), Player::North, Table::default())
+    }
+
+    #[test]
+    fn should_require_following_suit() {
+        let play = state([
+            (Player::North, vec![card(Rank::Ace, Suit::Hearts)]),
+            (Player::East, vec![card(Rank::Nine, Suit::Hearts), card(Rank::Ace, Suit::Clubs)]),
+            (Player::South, vec![card(Rank::Jack, Suit::Hearts)]),
+            (Player::West, vec![card(Rank::Queen, Suit::Hearts)]),
+        ])
+            .play(Player::North, card(Rank::Ace, Suit::Hearts), Suit::Spades)
+            .unwrap();
+
+        let result = play.play(Player::East, card(Rank::Ace, Suit::Clubs), Suit::Spades);
+
+        assert_eq!(result.unwrap_err(), "Must follow Hearts");
+    }
+
+    #[test]
+    fn should_require_heading_the_trick_when_able() {
+        let play = state([
+            (Player::North, vec![card(Rank::King, Suit::Hearts)]),
+            (Player::East, vec![card(Rank::Ace, Suit::Hearts), card(Rank::Jack, Suit::Hearts)]),
+            (Player::South, vec![card(Rank::Jack, Suit::Clubs)]),
+            (Player::West, vec![card(Rank::Queen, Suit::Clubs)]),
+        ])
+            .play(Player::North, card(Rank::King, Suit::Hearts), Suit::Spades)
+            .unwrap();
+
+        let result = play.play(Player::East, card(Rank::Jack, Suit::Hearts), Suit::Spades);
+
+        assert_eq!(result.unwrap_err(), "Must head the trick when able");
+    }
+
+    #[test]
+    fn should_require_trump_when_void_in_led_suit() {
+        let play = state([
+            (Player::North, vec![card(Rank::King, Suit::Hearts)]),
+            (Player::East, vec![card(Rank::Jack, Suit::Spades), card(Rank::Ace, Suit::Clubs)]),
+            (Player::South, vec![card(Rank::Jack, Suit::Clubs)]),
+            (Player::West, vec![card(Rank::Queen, Suit::Clubs)]),
+        ])
+            .play(Player::North, card(Rank::King, Suit::Hearts), Suit::Spades)
+            .unwrap();
+
+        let result = play.play(Player::East, card(Rank::Ace, Suit::Clubs), Suit::Spades);
+
+        assert_eq!(result.unwrap_err(), "Must trump with Spades when void in Hearts");
+    }
+
+    #[test]
+    fn should_award_trick_to_trump_and_first_of_identical_cards() {
+        let play = state([
+            (Player::North, vec![card(Rank::Ace, Suit::Hearts)]),
+            (Player::East, vec![card(Rank::Jack, Suit::Spades)]),
+            (Player::South, vec![card(Rank::Ten, Suit::Hearts)]),
+            (Player::West, vec![card(Rank::Jack, Suit::Spades)]),
+        ]);
+
+        let play = [
+            (Player::North, card(Rank::Ace, Suit::Hearts)),
+            (Player::East, card(Rank::Jack, Suit::Spades)),
+            (Player::South, card(Rank::Ten, Suit::Hearts)),
+            (Player::West, card(Rank::Jack, Suit::Spades)),
+        ]
+            .into_iter()
+            .fold(play, |play, (player, card)| play.play(player, card, Suit::Spades).unwrap());
+
+        assert!(play.is_finished());
+        assert_eq!(play.counters(), (BTreeMap::from([(TeamId::US, 0), (TeamId::THEM, 2)]), Some(TeamId::THEM)));
+        assert_eq!(play.next_to_play(), Player::East);
+    }
+
+    #[test]
+    fn should_reject_play_out_of_turn() {
+        let play = state([
+            (Player::North, vec![card(Rank::Ace, Suit::Hearts)]),
+            (Player::East, vec![card(Rank::Jack, Suit::Spades)]),
+            (Player::South, vec![card(Rank::Ten, Suit::Hearts)]),
+            (Player::West, vec![card(Rank::Jack, Suit::Spades)]),
+        ]);
+
+        assert!(play.play(Player::South, card(Rank::Ten, Suit::Hearts), Suit::Spades).is_err());
+    }
+}
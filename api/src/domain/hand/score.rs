@@ -0,0 +1,23 @@
+use std::collections::BTreeMap;
+use crate::domain::TeamId;
+
+/// Meld, trick points and hand totals of every team at the table. A team
+/// whose meld did not count has no meld entry.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HandScore {
+    pub meld: BTreeMap<TeamId, u32>,
+    pub tricks: BTreeMap<TeamId, u32>,
+    pub totals: BTreeMap<TeamId, i32>,
+}
+
+#[cfg(test)]
+impl HandScore {
+    /// Us/Them meld, tricks and totals at a two-team table.
+    pub fn us_them(meld: (u32, u32), tricks: (u32, u32), totals: (i32, i32)) -> Self {
+        Self {
+            meld: BTreeMap::from([(TeamId::US, meld.0), (TeamId::THEM, meld.1)]),
+            tricks: BTreeMap::from([(TeamId::US, tricks.0), (TeamId::THEM, tricks.1)]),
+            totals: BTreeMap::from([(TeamId::US, totals.0), (TeamId::THEM, totals.1)]),
+        }
+    }
+}
@@ -0,0 +1,240 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use crate::domain::{Game, PlayerId, PlayerProfile, TeamId};
+
+/// Whether the leaderboard ranks partnerships of players or single players.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardKind {
+    #[default]
+    Partnership,
+    Player,
+}
+
+/// How leaderboard entries are ranked. Every order but `Name` puts the best
+/// first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LeaderboardSort {
+    #[default]
+    WinRate,
+    Wins,
+    Margin,
+    GamesPlayed,
+    Name,
+}
+
+/// Games between an entry and one opponent, counted from the entry's side.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct HeadToHead {
+    pub opponent: Vec<PlayerProfile>,
+    pub wins: u32,
+    pub losses: u32,
+}
+
+/// The record of a partnership, or of a single player, over completed games.
+/// The margin of a game is the side's final score less the best opposing
+/// score.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LeaderboardEntry {
+    pub players: Vec<PlayerProfile>,
+    pub games_played: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub total_margin: i64,
+    pub head_to_head: Vec<HeadToHead>,
+}
+
+impl LeaderboardEntry {
+    pub fn name(&self) -> String {
+        self.players.iter().map(|player| player.name.as_str()).collect::<Vec<_>>().join(" & ")
+    }
+
+    pub fn win_rate(&self) -> Option<f64> {
+        (self.games_played > 0).then(|| self.wins as f64 / self.games_played as f64)
+    }
+
+    pub fn average_margin(&self) -> Option<f64> {
+        (self.games_played > 0).then(|| self.total_margin as f64 / self.games_played as f64)
+    }
+}
+
+/// Ranked records of every partnership or player seen in completed games.
+/// Only games whose seats were all assigned to players count.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Leaderboard {
+    entries: Vec<LeaderboardEntry>,
+}
+
+/// One side of a completed game, as the players on it.
+struct Side {
+    key: Vec<PlayerId>,
+    players: Vec<PlayerProfile>,
+    team: TeamId,
+}
+
+impl Leaderboard {
+    pub fn build<'a>(games: impl IntoIterator<Item = &'a Game>, kind: LeaderboardKind) -> Self {
+        let mut entries: HashMap<Vec<PlayerId>, LeaderboardEntry> = HashMap::new();
+        let mut head_to_head: HashMap<(Vec<PlayerId>, Vec<PlayerId>), HeadToHead> = HashMap::new();
+
+        for game in games {
+            let Some(winner) = game.winning_team() else {
+                continue;
+            };
+            let Some(sides) = Self::sides(game, kind) else {
+                continue;
+            };
+
+            let totals = game.team_totals();
+            let score = |team: TeamId| totals.get(&team).copied().unwrap_or(0) as i64;
+
+            for side in &sides {
+                let best_opponent = totals.keys()
+                    .filter(|team| **team != side.team)
+                    .map(|team| score(*team))
+                    .max()
+                    .unwrap_or(0);
+
+                let entry = entries.entry(side.key.clone()).or_insert_with(|| LeaderboardEntry {
+                    players: side.players.clone(),
+                    ..LeaderboardEntry::default()
+                });
+                entry.games_played += 1;
+                entry.total_margin += score(side.team) - best_opponent;
+                match side.team == winner {
+                    true => entry.wins += 1,
+                    false => entry.losses += 1,
+                }
+
+                for opponent in sides.iter().filter(|other| other.team != side.team) {
+                    let record = head_to_head
+                        .entry((side.key.clone(), opponent.key.clone()))
+                        .or_insert_with(|| HeadToHead { opponent: opponent.players.clone(), ..HeadToHead::default() });
+                    if side.team == winner {
+                        record.wins += 1;
+                    } else if opponent.team == winner {
+                        record.losses += 1;
+                    }
+                }
+            }
+        }
+
+        for ((key, _), record) in head_to_head {
+            if let Some(entry) = entries.get_mut(&key) {
+                entry.head_to_head.push(record);
+            }
+        }
+
+        let mut entries: Vec<LeaderboardEntry> = entries.into_values().collect();
+        for entry in &mut entries {
+            entry.head_to_head.sort_by_key(|record| record.opponent.iter().map(|player| player.name.clone()).collect::<Vec<_>>());
+        }
+
+        Self { entries }.sorted(LeaderboardSort::default())
+    }
+
+    /// The sides of a completed game, or none when a seat had no player
+    /// assigned on the final hand.
+    fn sides(game: &Game, kind: LeaderboardKind) -> Option<Vec<Side>> {
+        let mut sides = Vec::new();
+        for (team, mut players) in game.seated_teams()? {
+            players.sort_by(|a, b| a.name.cmp(&b.name).then(a.id.0.cmp(&b.id.0)));
+
+            match kind {
+                LeaderboardKind::Partnership => sides.push(Side {
+                    key: players.iter().map(|player| player.id).collect(),
+                    players,
+                    team,
+                }),
+                LeaderboardKind::Player => sides.extend(players.into_iter().map(|player| Side {
+                    key: vec![player.id],
+                    players: vec![player],
+                    team,
+                })),
+            }
+        }
+        Some(sides)
+    }
+
+    /// Ranks the entries by `sort`, breaking ties by name.
+    pub fn sorted(self, sort: LeaderboardSort) -> Self {
+        let descending = |a: Option<f64>, b: Option<f64>| b.partial_cmp(&a).unwrap_or(Ordering::Equal);
+        let mut entries = self.entries;
+        entries.sort_by(|a, b| {
+            let order = match sort {
+                LeaderboardSort::WinRate => descending(a.win_rate(), b.win_rate()).then(b.wins.cmp(&a.wins)),
+                LeaderboardSort::Wins => b.wins.cmp(&a.wins).then(a.losses.cmp(&b.losses)),
+                LeaderboardSort::Margin => descending(a.average_margin(), b.average_margin()),
+                LeaderboardSort::GamesPlayed => b.games_played.cmp(&a.games_played),
+                LeaderboardSort::Name => Ordering::Equal,
+            };
+            order.then_with(|| a.name().cmp(&b.name()))
+        });
+        Self { entries }
+    }
+
+    pub fn entries(&self) -> &[LeaderboardEntry] {
+        &self.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hand, HandScore, HandState, Player, Suit, Winner};
+
+    fn finished(us: [&PlayerProfile; 2], them: [&PlayerProfile; 2], us_total: i32, them_total: i32) -> Game {
+        let hand = Hand::new(Player::South)
+            .set_state(
+                HandState::Completed { bidder: Player::North, bid_amount: 60, trump: Suit::Spades },
+                HandScore::us_them((20, 20), (25, 25), (us_total, them_total)),
+            )
+            .with_players(HashMap::from([
+                (Player::North, us[0].clone()),
+                (Player::South, us[1].clone()),
+                (Player::East, them[0].clone()),
+                (Player::West, them[1].clone()),
+            ]));
+        let winner = if us_total > them_total { TeamId::US } else { TeamId::THEM };
+
+        Game::new(Player::South)
+            .with_completed_hands(vec![hand])
+            .with_winner(Winner::Team(winner), chrono::Utc::now())
+    }
+
+    #[test]
+    fn should_rank_partnerships_with_head_to_head() {
+        let [ann, bob, cat, dan, eve, fay] = ["Ann", "Bob", "Cat", "Dan", "Eve", "Fay"].map(PlayerProfile::new);
+        let games = [
+            finished([&ann, &bob], [&cat, &dan], 520, 400),
+            finished([&ann, &bob], [&cat, &dan], 500, 510),
+            finished([&ann, &bob], [&eve, &fay], 505, 300),
+        ];
+
+        let board = Leaderboard::build(&games, LeaderboardKind::Partnership);
+        let top = &board.entries()[0];
+
+        assert_eq!(board.entries().len(), 3);
+        assert_eq!(top.name(), "Ann & Bob");
+        assert_eq!((top.wins, top.losses), (2, 1));
+        assert_eq!(top.average_margin(), Some((120.0 - 10.0 + 205.0) / 3.0));
+        assert_eq!(top.head_to_head.len(), 2);
+        assert_eq!((top.head_to_head[0].wins, top.head_to_head[0].losses), (1, 1));
+        assert_eq!(board.sorted(LeaderboardSort::Name).entries()[2].name(), "Eve & Fay");
+    }
+
+    #[test]
+    fn should_rank_players_and_skip_unseated_games() {
+        let [ann, bob, cat, dan] = ["Ann", "Bob", "Cat", "Dan"].map(PlayerProfile::new);
+        let unseated = finished([&ann, &bob], [&cat, &dan], 520, 400).with_completed_hands(vec![]);
+        let games = [finished([&ann, &bob], [&cat, &dan], 520, 400), unseated];
+
+        let board = Leaderboard::build(&games, LeaderboardKind::Player);
+
+        assert_eq!(board.entries().len(), 4);
+        assert!(board.entries().iter().all(|entry| entry.games_played == 1));
+        assert_eq!(board.entries()[0].head_to_head.len(), 2);
+    }
+}
@@ -0,0 +1,202 @@
+use std::collections::BTreeMap;
+use serde::{Serialize, Deserialize};
+use crate::domain::{Game, GameId, MatchError, MatchId, Roster, RuleSet, TeamId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MatchState {
+    InProgress,
+    Completed,
+}
+
+/// A series of games between the same partnerships, won by the first team
+/// to win `wins_required` games. Every game is played with the match's rule
+/// set, and each new game is dealt by the seat after the last dealer of the
+/// game before.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    id: MatchId,
+    rule_set: RuleSet,
+    wins_required: u32,
+    games: Vec<GameId>,
+    wins: BTreeMap<TeamId, u32>,
+    state: MatchState,
+    winner: Option<TeamId>,
+    version: usize,
+}
+
+impl Match {
+    /// Starts a match with `first_game` as its opening game.
+    pub fn new(first_game: &Game, wins_required: u32) -> Result<Self, MatchError> {
+        if wins_required == 0 {
+            return Err(MatchError::InvalidWinsRequired(wins_required));
+        }
+
+        let rule_set = first_game.rule_set().clone();
+        Ok(Self {
+            id: MatchId::new(),
+            wins: rule_set.table.team_ids().map(|team| (team, 0)).collect(),
+            rule_set,
+            wins_required,
+            games: vec![first_game.id()],
+            state: MatchState::InProgress,
+            winner: None,
+            version: 0,
+        })
+    }
+
+    pub fn id(&self) -> MatchId {
+        self.id
+    }
+
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+
+    pub fn wins_required(&self) -> u32 {
+        self.wins_required
+    }
+
+    /// The games of the match in the order they were played.
+    pub fn games(&self) -> &[GameId] {
+        &self.games
+    }
+
+    pub fn current_game(&self) -> Option<GameId> {
+        self.games.last().copied()
+    }
+
+    pub fn wins(&self) -> &BTreeMap<TeamId, u32> {
+        &self.wins
+    }
+
+    pub fn state(&self) -> MatchState {
+        self.state
+    }
+
+    pub fn winner(&self) -> Option<TeamId> {
+        self.winner
+    }
+
+    /// How many times the match has been saved. A repository refuses to
+    /// save a match whose version is behind the one it holds.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    pub(crate) fn with_version(self, version: usize) -> Self {
+        Self { version, ..self }
+    }
+
+    /// Whether the result of `game_id` already counts towards the match:
+    /// every game before the current one, and the last game of a match that
+    /// is over.
+    pub fn has_credited(&self, game_id: GameId) -> bool {
+        self.games.contains(&game_id) && (self.current_game() != Some(game_id) || self.state == MatchState::Completed)
+    }
+
+    /// Credits the winner of the current game, completing the match when
+    /// that team has the wins required. Otherwise the next game is returned
+    /// too, ready to be saved: same rules, same players, dealt by the seat
+    /// after the finished game's last dealer.
+    pub fn record_game(&self, game: &Game) -> Result<(Self, Option<Game>), MatchError> {
+        if self.state == MatchState::Completed {
+            return Err(MatchError::MatchOver);
+        }
+        if self.current_game() != Some(game.id()) {
+            return Err(MatchError::NotCurrentGame(game.id()));
+        }
+        let Some(team) = game.winning_team() else {
+            return Err(MatchError::GameNotWon(game.id()));
+        };
+
+        let mut wins = self.wins.clone();
+        let won = wins.entry(team).or_default();
+        *won += 1;
+
+        if *won >= self.wins_required {
+            tracing::info!("Match {} won by {}", self.id, team);
+            return Ok((Self { wins, state: MatchState::Completed, winner: Some(team), ..self.clone() }, None));
+        }
+
+        let next = self.next_game(game);
+        let mut games = self.games.clone();
+        games.push(next.id());
+        Ok((Self { wins, games, ..self.clone() }, Some(next)))
+    }
+
+    fn next_game(&self, previous: &Game) -> Game {
+        let hands = previous.scored_hands();
+        let last_dealer = hands.last().map(|hand| hand.dealer()).unwrap_or(previous.current_dealer());
+        let dealer = self.rule_set.table.next_clockwise(last_dealer).unwrap_or(last_dealer);
+        let seating = previous.roster().seating(hands.len().max(1));
+
+        Game::new(dealer)
+            .with_rule_set(self.rule_set.clone())
+            .with_roster(Roster::new(seating.into_iter().collect()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use crate::domain::{GameState, Player, Winner};
+
+    fn won_by(game: &Game, team: TeamId) -> Game {
+        game.add_completed_hand_with_scores(500, 300, Player::North).with_winner(Winner::Team(team), Utc::now())
+    }
+
+    #[test]
+    fn should_require_at_least_one_win() {
+        assert_eq!(Match::new(&Game::new(Player::North), 0), Err(MatchError::InvalidWinsRequired(0)));
+    }
+
+    #[test]
+    fn should_start_next_game_with_rotated_dealer() {
+        let first = Game::new(Player::North);
+        let game_match = Match::new(&first, 2).unwrap();
+
+        let (game_match, next) = game_match.record_game(&won_by(&first, TeamId::US)).unwrap();
+        let next = next.unwrap();
+
+        assert_eq!(game_match.wins()[&TeamId::US], 1);
+        assert_eq!(game_match.games(), &[first.id(), next.id()]);
+        assert_eq!(game_match.state(), MatchState::InProgress);
+        // The completed hand was dealt by South, so West deals next.
+        assert_eq!(next.current_dealer(), Player::West);
+        assert_eq!(next.state(), GameState::WaitingToStart);
+    }
+
+    #[test]
+    fn should_complete_when_a_team_has_the_wins_required() {
+        let first = Game::new(Player::North);
+        let game_match = Match::new(&first, 2).unwrap();
+
+        let (game_match, second) = game_match.record_game(&won_by(&first, TeamId::THEM)).unwrap();
+        let second = second.unwrap();
+        assert_eq!(game_match.record_game(&won_by(&first, TeamId::US)), Err(MatchError::NotCurrentGame(first.id())));
+
+        let (game_match, third) = game_match.record_game(&won_by(&second, TeamId::THEM)).unwrap();
+
+        assert!(third.is_none());
+        assert_eq!(game_match.state(), MatchState::Completed);
+        assert_eq!(game_match.winner(), Some(TeamId::THEM));
+        assert_eq!(game_match.record_game(&won_by(&second, TeamId::THEM)), Err(MatchError::MatchOver));
+    }
+
+    #[test]
+    fn should_have_credited_every_game_before_the_current_one() {
+        let first = Game::new(Player::North);
+        let game_match = Match::new(&first, 2).unwrap();
+        assert!(!game_match.has_credited(first.id()));
+
+        let (game_match, second) = game_match.record_game(&won_by(&first, TeamId::US)).unwrap();
+        let second = second.unwrap();
+        assert!(game_match.has_credited(first.id()));
+        assert!(!game_match.has_credited(second.id()));
+
+        let (game_match, _) = game_match.record_game(&won_by(&second, TeamId::US)).unwrap();
+        assert!(game_match.has_credited(second.id()));
+        assert!(!game_match.has_credited(Game::new(Player::North).id()));
+    }
+}
@@ -0,0 +1,15 @@
+use crate::domain::GameId;
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum MatchError {
+    #[error("A match needs at least one win, not {0}")]
+    InvalidWinsRequired(u32),
+    #[error("Game {0} is not the match's current game")]
+    NotCurrentGame(GameId),
+    #[error("Game {0} has not been won")]
+    GameNotWon(GameId),
+    #[error("Game {0} has already been credited to its match")]
+    GameCredited(GameId),
+    #[error("Match is over")]
+    MatchOver,
+}
@@ -0,0 +1,7 @@
+pub use match_error::MatchError;
+pub use game_match::{Match, MatchState};
+pub use repository::{MatchRepository, MatchRepositoryError};
+
+pub mod match_error;
+pub mod game_match;
+pub mod repository;
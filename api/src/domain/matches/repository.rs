@@ -0,0 +1,18 @@
+use crate::domain::{GameId, Match, MatchId};
+
+#[async_trait::async_trait]
+pub trait MatchRepository: Send + Sync {
+    async fn find_by_id(&self, id: MatchId) -> Result<Option<Match>, MatchRepositoryError>;
+    async fn find_by_game(&self, game_id: GameId) -> Result<Option<Match>, MatchRepositoryError>;
+    /// Saves `game_match` if it is the version last saved, so that two
+    /// changes made from the same copy cannot overwrite each other.
+    async fn save(&self, game_match: Match) -> Result<(), MatchRepositoryError>;
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum MatchRepositoryError {
+    #[error("Match isn't found: {0}")]
+    MatchDoesNotExist(MatchId),
+    #[error("Match {match_id} was changed meanwhile: expected version {expected}, found {actual}")]
+    Conflict { match_id: MatchId, expected: usize, actual: usize },
+}
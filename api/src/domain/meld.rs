@@ -0,0 +1,217 @@
+use serde::{Serialize, Deserialize};
+use crate::domain::{Card, DeckKind, Rank, Suit};
+
+const RUN_RANKS: [Rank; 5] = [Rank::Ace, Rank::Ten, Rank::King, Rank::Queen, Rank::Jack];
+const MULTIPLES: [&str; 7] = ["Double", "Triple", "Quadruple", "Quintuple", "Sextuple", "Septuple", "Octuple"];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MeldItem {
+    pub name: String,
+    pub points: u32,
+}
+
+impl MeldItem {
+    fn new(name: impl Into<String>, points: u32) -> Self {
+        Self { name: name.into(), points }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MeldBreakdown {
+    pub items: Vec<MeldItem>,
+}
+
+impl MeldBreakdown {
+    pub fn total(&self) -> u32 {
+        self.items.iter().map(|item| item.points).sum()
+    }
+}
+
+/// Names and scores `count` copies of a meld. Doubles score `double`, and
+/// every further copy adds another `double`, up to `most` copies.
+fn multiple(name: &str, count: u32, most: u32, single: u32, double: u32) -> Option<MeldItem> {
+    match count.min(most) {
+        0 => None,
+        1 => Some(MeldItem::new(name, single)),
+        count => Some(MeldItem::new(
+            format!("{} {}", MULTIPLES[(count - 2) as usize], name),
+            double * (count - 1),
+        )),
+    }
+}
+
+/// Works out a player's meld from the cards they lay down. Cards of the
+/// `NoMarriage` pseudo-suit are ignored, and a `NoMarriage` trump scores no
+/// runs, royal marriages or dix. Larger decks score triple and quadruple
+/// runs and arounds, and pinochles up to two per deck.
+pub fn calculate(cards: &[Card], trump: Suit, deck_kind: DeckKind) -> MeldBreakdown {
+    let count = |rank: Rank, suit: Suit| -> u32 {
+        cards.iter().filter(|card| card.rank == rank && card.suit == suit).count() as u32
+    };
+
+    let mut items = Vec::new();
+    let most_sets = std::cmp::max(2, deck_kind.decks());
+    let most_pinochles = std::cmp::max(4, 2 * deck_kind.decks());
+    let has_trump = trump != Suit::NoMarriage;
+
+    let runs = if has_trump {
+        RUN_RANKS.iter().map(|rank| count(*rank, trump)).min().unwrap_or(0)
+    } else {
+        0
+    };
+    items.extend(multiple(&format!("Run in {}", trump), runs, most_sets, 15, 150));
+
+    for suit in Suit::PLAYABLE {
+        let marriages = count(Rank::King, suit).min(count(Rank::Queen, suit));
+        if has_trump && suit == trump {
+            for _ in 0..marriages.saturating_sub(runs) {
+                items.push(MeldItem::new(format!("Royal Marriage in {}", suit), 4));
+            }
+        } else {
+            for _ in 0..marriages {
+                items.push(MeldItem::new(format!("Marriage in {}", suit), 2));
+            }
+        }
+    }
+
+    let pinochles = count(Rank::Queen, Suit::Spades).min(count(Rank::Jack, Suit::Diamonds));
+    items.extend(multiple("Pinochle", pinochles, most_pinochles, 4, 30));
+
+    for (rank, name, single, double) in [
+        (Rank::Ace, "Aces", 10, 100),
+        (Rank::King, "Kings", 8, 80),
+        (Rank::Queen, "Queens", 6, 60),
+        (Rank::Jack, "Jacks", 4, 40),
+    ] {
+        let arounds = Suit::PLAYABLE.iter().map(|suit| count(rank, *suit)).min().unwrap_or(0);
+        items.extend(multiple(&format!("{} Around", name), arounds, most_sets, single, double));
+    }
+
+    if has_trump && deck_kind.has_nines() {
+        for _ in 0..count(Rank::Nine, trump) {
+            items.push(MeldItem::new("Dix", 1));
+        }
+    }
+
+    MeldBreakdown { items }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cards(list: &[(Rank, Suit)]) -> Vec<Card> {
+        list.iter().map(|(rank, suit)| Card::new(*rank, *suit)).collect()
+    }
+
+    fn run(suit: Suit) -> Vec<(Rank, Suit)> {
+        RUN_RANKS.iter().map(|rank| (*rank, suit)).collect()
+    }
+
+    fn names(breakdown: &MeldBreakdown) -> Vec<&str> {
+        breakdown.items.iter().map(|item| item.name.as_str()).collect()
+    }
+
+    #[test]
+    fn should_score_run_without_counting_its_royal_marriage() {
+        let breakdown = calculate(&cards(&run(Suit::Hearts)), Suit::Hearts, DeckKind::DoubleDeck);
+
+        assert_eq!(names(&breakdown), vec!["Run in Hearts"]);
+        assert_eq!(breakdown.total(), 15);
+    }
+
+    #[test]
+    fn should_score_extra_royal_marriage_alongside_run() {
+        let mut hand = run(Suit::Hearts);
+        hand.extend([(Rank::King, Suit::Hearts), (Rank::Queen, Suit::Hearts)]);
+
+        let breakdown = calculate(&cards(&hand), Suit::Hearts, DeckKind::DoubleDeck);
+
+        assert_eq!(names(&breakdown), vec!["Run in Hearts", "Royal Marriage in Hearts"]);
+        assert_eq!(breakdown.total(), 19);
+    }
+
+    #[test]
+    fn should_score_double_run() {
+        let mut hand = run(Suit::Clubs);
+        hand.extend(run(Suit::Clubs));
+
+        let breakdown = calculate(&cards(&hand), Suit::Clubs, DeckKind::DoubleDeck);
+
+        assert_eq!(names(&breakdown), vec!["Double Run in Clubs"]);
+        assert_eq!(breakdown.total(), 150);
+    }
+
+    #[test]
+    fn should_score_marriages_outside_trump() {
+        let hand = cards(&[
+            (Rank::King, Suit::Spades), (Rank::Queen, Suit::Spades),
+            (Rank::King, Suit::Diamonds), (Rank::Queen, Suit::Diamonds),
+            (Rank::King, Suit::Clubs), (Rank::Queen, Suit::Clubs),
+        ]);
+
+        let breakdown = calculate(&hand, Suit::Clubs, DeckKind::DoubleDeck);
+
+        assert_eq!(names(&breakdown), vec!["Marriage in Spades", "Royal Marriage in Clubs", "Marriage in Diamonds"]);
+        assert_eq!(breakdown.total(), 8);
+    }
+
+    #[test]
+    fn should_score_pinochle_and_double_pinochle() {
+        let single = cards(&[(Rank::Queen, Suit::Spades), (Rank::Jack, Suit::Diamonds)]);
+        let double = cards(&[
+            (Rank::Queen, Suit::Spades), (Rank::Jack, Suit::Diamonds),
+            (Rank::Queen, Suit::Spades), (Rank::Jack, Suit::Diamonds),
+        ]);
+
+        assert_eq!(calculate(&single, Suit::Hearts, DeckKind::DoubleDeck).total(), 4);
+        assert_eq!(names(&calculate(&double, Suit::Hearts, DeckKind::DoubleDeck)), vec!["Double Pinochle"]);
+        assert_eq!(calculate(&double, Suit::Hearts, DeckKind::DoubleDeck).total(), 30);
+    }
+
+    #[test]
+    fn should_score_arounds_and_doubles() {
+        let aces: Vec<(Rank, Suit)> = Suit::PLAYABLE.iter().map(|suit| (Rank::Ace, *suit)).collect();
+        let mut double_kings: Vec<(Rank, Suit)> = Suit::PLAYABLE.iter().map(|suit| (Rank::King, *suit)).collect();
+        double_kings.extend(double_kings.clone());
+
+        let mut hand = aces;
+        hand.extend(double_kings);
+
+        let breakdown = calculate(&cards(&hand), Suit::NoMarriage, DeckKind::DoubleDeck);
+
+        assert_eq!(names(&breakdown), vec!["Aces Around", "Double Kings Around"]);
+        assert_eq!(breakdown.total(), 90);
+    }
+
+    #[test]
+    fn should_scale_melds_with_deck_count() {
+        let mut triple_run = run(Suit::Spades);
+        triple_run.extend(run(Suit::Spades));
+        triple_run.extend(run(Suit::Spades));
+        let triple_aces: Vec<(Rank, Suit)> = (0..3)
+            .flat_map(|_| Suit::PLAYABLE.iter().map(|suit| (Rank::Ace, *suit)))
+            .collect();
+
+        assert_eq!(names(&calculate(&cards(&triple_run), Suit::Spades, DeckKind::DoubleDeck)), vec!["Double Run in Spades"]);
+        assert_eq!(names(&calculate(&cards(&triple_run), Suit::Spades, DeckKind::TripleDeck)), vec!["Triple Run in Spades"]);
+        assert_eq!(calculate(&cards(&triple_run), Suit::Spades, DeckKind::TripleDeck).total(), 300);
+        assert_eq!(calculate(&cards(&triple_aces), Suit::NoMarriage, DeckKind::QuadrupleDeck).total(), 200);
+    }
+
+    #[test]
+    fn should_score_dix_only_when_deck_has_nines() {
+        let hand = cards(&[(Rank::Nine, Suit::Spades), (Rank::Nine, Suit::Spades), (Rank::Nine, Suit::Hearts)]);
+
+        assert_eq!(calculate(&hand, Suit::Spades, DeckKind::SingleDeck).total(), 2);
+        assert_eq!(calculate(&hand, Suit::Spades, DeckKind::DoubleDeck).total(), 0);
+    }
+
+    #[test]
+    fn should_not_score_trump_melds_without_trump() {
+        let breakdown = calculate(&cards(&run(Suit::Hearts)), Suit::NoMarriage, DeckKind::DoubleDeck);
+
+        assert_eq!(names(&breakdown), vec!["Marriage in Hearts"]);
+        assert_eq!(breakdown.total(), 2);
+    }
+}
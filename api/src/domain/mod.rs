@@ -1,17 +1,46 @@
 mod value;
+mod rules;
+mod table;
+mod roster;
+mod stats;
+mod leaderboard;
+mod rating;
 mod hand;
+mod meld;
+mod deck;
 
 mod game;
+mod matches;
+mod tournament;
 
 pub use value::{
     GameId,
+    MatchId,
+    TournamentId,
     HandId,
+    PlayerId,
     Player,
+    Call,
+    Card,
+    Rank,
+    DeckKind,
     Team,
+    TeamId,
+    Winner,
     Suit,
     GameState,
     HandState
 };
 
-pub use hand::{Hand, HandError, HandRepository, HandRepositoryError};
-pub use game::{Game, GameError, GameRepository, GameRepositoryError};
\ No newline at end of file
+pub use rules::{AllPassRule, RuleSet, RuleSetPreset};
+pub use table::{Partnership, Table};
+pub use roster::{PlayerProfile, Roster, SeatAssignment};
+pub use stats::{PlayerStats, StatsReport};
+pub use rating::{RatingRepository, RatingRepositoryError, RatingSubject, Ratings};
+pub use leaderboard::{HeadToHead, Leaderboard, LeaderboardEntry, LeaderboardKind, LeaderboardSort};
+pub use meld::MeldItem;
+pub use deck::{Deal, Dealer, Deck};
+pub use hand::{Auction, AuctionCall, Hand, HandCorrection, HandRecord, HandScore, PlayState, PlayedCard, HandError, HandRepository, HandRepositoryError};
+pub use game::{Game, GameAction, GameError, GameEvent, HandEdit, MeldEntry, TricksEntry, EventStore, EventStoreError, GameRepository, GameRepositoryError};
+pub use tournament::{Round, Standing, TableAssignment, Tournament, TournamentError, TournamentRepository, TournamentRepositoryError};
+pub use matches::{Match, MatchError, MatchRepository, MatchRepositoryError, MatchState};
\ No newline at end of file
@@ -0,0 +1,5 @@
+pub use ratings::{RatingSubject, Ratings};
+pub use repository::{RatingRepository, RatingRepositoryError};
+
+pub mod ratings;
+pub mod repository;
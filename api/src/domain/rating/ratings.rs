@@ -0,0 +1,243 @@
+use std::collections::{HashMap, HashSet};
+use chrono::{DateTime, Utc};
+use crate::domain::{Game, GameId, GameState, PlayerId, PlayerProfile, TeamId};
+
+/// Who a rating belongs to: a player, or a partnership of players listed in
+/// id order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RatingSubject {
+    Player(PlayerId),
+    Partnership(Vec<PlayerId>),
+}
+
+/// One rating update, kept so the history can be charted.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RatingChange {
+    pub subject: RatingSubject,
+    pub game_id: GameId,
+    pub before: f64,
+    pub after: f64,
+    pub at: DateTime<Utc>,
+}
+
+/// Elo ratings of every player and partnership seen in completed games.
+///
+/// The winners of a game take points from each losing side: 32 times the
+/// share of the result they were not expected to get, scaled by
+/// ln(1 + margin / 10) and damped when the favorite wins, so that large
+/// wins count more without runaway favorites. A side plays at the average
+/// rating of its players; a partnership of two or more also has a rating of
+/// its own.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Ratings {
+    ratings: HashMap<RatingSubject, f64>,
+    history: Vec<RatingChange>,
+    names: HashMap<PlayerId, String>,
+    rated_games: HashSet<GameId>,
+    version: usize,
+}
+
+/// A losing or winning side of a game, as rated.
+struct Side {
+    team: TeamId,
+    players: Vec<PlayerId>,
+    score: i32,
+}
+
+impl Ratings {
+    pub const INITIAL: f64 = 1500.0;
+    const K: f64 = 32.0;
+
+    /// Rates every completed game in the order the games were completed.
+    pub fn replay<'a>(games: impl IntoIterator<Item = &'a Game>) -> Self {
+        let mut completed: Vec<&Game> = games.into_iter().filter(|game| game.result().is_some()).collect();
+        completed.sort_by_key(|game| game.result().map(|result| result.completed_at));
+
+        completed.into_iter().fold(Self::default(), |ratings, game| ratings.rate(game))
+    }
+
+    pub fn rating(&self, subject: &RatingSubject) -> f64 {
+        self.ratings.get(subject).copied().unwrap_or(Self::INITIAL)
+    }
+
+    pub fn ratings(&self) -> &HashMap<RatingSubject, f64> {
+        &self.ratings
+    }
+
+    pub fn history(&self) -> &[RatingChange] {
+        &self.history
+    }
+
+    pub fn name(&self, player: PlayerId) -> Option<&str> {
+        self.names.get(&player).map(String::as_str)
+    }
+
+    pub fn is_rated(&self, game_id: GameId) -> bool {
+        self.rated_games.contains(&game_id)
+    }
+
+    /// How many times the ratings have been saved. A repository refuses to
+    /// save ratings whose version is behind the one it holds.
+    pub fn version(&self) -> usize {
+        self.version
+    }
+
+    pub(crate) fn with_version(self, version: usize) -> Self {
+        Self { version, ..self }
+    }
+
+    /// Updates the ratings of everyone in a completed game. Games that are
+    /// not completed, were already rated or had unassigned seats leave the
+    /// ratings as they are.
+    pub fn rate(&self, game: &Game) -> Self {
+        let (Some(result), Some(seated)) = (game.result(), game.seated_teams()) else {
+            return self.clone();
+        };
+        if game.state() != GameState::Completed || self.is_rated(game.id()) {
+            return self.clone();
+        }
+
+        let Some(winning_team) = game.winning_team() else {
+            return self.clone();
+        };
+        let totals = game.team_totals();
+        let mut sides: Vec<Side> = seated.iter()
+            .map(|(team, players)| Side {
+                team: *team,
+                players: Self::ordered(players),
+                score: totals.get(team).copied().unwrap_or(0),
+            })
+            .collect();
+        let Some(position) = sides.iter().position(|side| side.team == winning_team) else {
+            return self.clone();
+        };
+        let winner = sides.remove(position);
+
+        // Kept in the order subjects are first met, so the history reads the
+        // same on every replay.
+        let mut deltas: Vec<(RatingSubject, f64)> = Vec::new();
+        let mut add = |subject: RatingSubject, delta: f64| match deltas.iter_mut().find(|(known, _)| *known == subject) {
+            Some((_, total)) => *total += delta,
+            None => deltas.push((subject, delta)),
+        };
+        for loser in &sides {
+            let margin = (winner.score - loser.score).max(10) as f64;
+
+            let (winning, losing) = (self.side_rating(&winner.players), self.side_rating(&loser.players));
+            let delta = Self::delta(winning, losing, margin);
+            for player in &winner.players {
+                add(RatingSubject::Player(*player), delta);
+            }
+            for player in &loser.players {
+                add(RatingSubject::Player(*player), -delta);
+            }
+
+            if winner.players.len() > 1 && loser.players.len() > 1 {
+                let (winning, losing) = (RatingSubject::Partnership(winner.players.clone()), RatingSubject::Partnership(loser.players.clone()));
+                let delta = Self::delta(self.rating(&winning), self.rating(&losing), margin);
+                add(winning, delta);
+                add(losing, -delta);
+            }
+        }
+
+        let mut ratings = self.clone();
+        for (subject, delta) in deltas {
+            let before = self.rating(&subject);
+            let change = RatingChange { subject, game_id: game.id(), before, after: before + delta, at: result.completed_at };
+            ratings.ratings.insert(change.subject.clone(), change.after);
+            ratings.history.push(change);
+        }
+        for player in seated.iter().flat_map(|(_, players)| players) {
+            ratings.names.insert(player.id, player.name.clone());
+        }
+        ratings.rated_games.insert(game.id());
+        ratings
+    }
+
+    fn ordered(players: &[PlayerProfile]) -> Vec<PlayerId> {
+        let mut ids: Vec<PlayerId> = players.iter().map(|player| player.id).collect();
+        ids.sort_by_key(|id| id.0);
+        ids
+    }
+
+    fn side_rating(&self, players: &[PlayerId]) -> f64 {
+        let total: f64 = players.iter().map(|player| self.rating(&RatingSubject::Player(*player))).sum();
+        total / players.len().max(1) as f64
+    }
+
+    /// Points the winner takes from the loser.
+    fn delta(winning: f64, losing: f64, margin: f64) -> f64 {
+        let expected = 1.0 / (1.0 + 10f64.powf((losing - winning) / 400.0));
+        let margin_multiplier = (1.0 + margin / 10.0).ln() * 2.2 / ((winning - losing) * 0.001 + 2.2);
+        Self::K * margin_multiplier * (1.0 - expected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Hand, HandScore, HandState, Player, Suit, Winner};
+
+    fn finished_at(at: DateTime<Utc>, us: [&PlayerProfile; 2], them: [&PlayerProfile; 2], us_total: i32, them_total: i32) -> Game {
+        let hand = Hand::new(Player::South)
+            .set_state(
+                HandState::Completed { bidder: Player::North, bid_amount: 60, trump: Suit::Spades },
+                HandScore::us_them((20, 20), (25, 25), (us_total, them_total)),
+            )
+            .with_players(HashMap::from([
+                (Player::North, us[0].clone()),
+                (Player::South, us[1].clone()),
+                (Player::East, them[0].clone()),
+                (Player::West, them[1].clone()),
+            ]));
+        let winner = if us_total > them_total { TeamId::US } else { TeamId::THEM };
+
+        Game::new(Player::South)
+            .with_completed_hands(vec![hand])
+            .with_winner(Winner::Team(winner), at)
+    }
+
+    #[test]
+    fn should_move_players_and_partnerships_by_the_same_amount() {
+        let [ann, bob, cat, dan] = ["Ann", "Bob", "Cat", "Dan"].map(PlayerProfile::new);
+        let game = finished_at(Utc::now(), [&ann, &bob], [&cat, &dan], 520, 400);
+
+        let ratings = Ratings::default().rate(&game);
+        let gain = ratings.rating(&RatingSubject::Player(ann.id)) - Ratings::INITIAL;
+
+        assert!(gain > 0.0);
+        assert_eq!(ratings.rating(&RatingSubject::Player(cat.id)), Ratings::INITIAL - gain);
+        assert_eq!(ratings.rating(&RatingSubject::Partnership(Ratings::ordered(&[ann.clone(), bob.clone()]))), Ratings::INITIAL + gain);
+        assert_eq!(ratings.history().len(), 6);
+        assert_eq!(ratings.name(dan.id), Some("Dan"));
+        assert_eq!(ratings.rate(&game), ratings);
+    }
+
+    #[test]
+    fn should_reward_larger_margins_and_upsets() {
+        let [ann, bob, cat, dan] = ["Ann", "Bob", "Cat", "Dan"].map(PlayerProfile::new);
+        let gain = |ratings: &Ratings| ratings.rating(&RatingSubject::Player(ann.id)) - Ratings::INITIAL;
+
+        let close = Ratings::default().rate(&finished_at(Utc::now(), [&ann, &bob], [&cat, &dan], 510, 500));
+        let blowout = Ratings::default().rate(&finished_at(Utc::now(), [&ann, &bob], [&cat, &dan], 600, 200));
+        assert!(gain(&blowout) > gain(&close));
+
+        let favored = Ratings::default().rate(&finished_at(Utc::now(), [&ann, &bob], [&cat, &dan], 520, 400));
+        let again = favored.rate(&finished_at(Utc::now(), [&ann, &bob], [&cat, &dan], 520, 400));
+        let upset = favored.rate(&finished_at(Utc::now(), [&ann, &bob], [&cat, &dan], 400, 520));
+        let first_gain = gain(&favored);
+        assert!(gain(&again) - first_gain < first_gain);
+        assert!(first_gain - gain(&upset) > first_gain);
+    }
+
+    #[test]
+    fn should_replay_games_in_completion_order() {
+        let [ann, bob, cat, dan] = ["Ann", "Bob", "Cat", "Dan"].map(PlayerProfile::new);
+        let first = finished_at(Utc::now() - chrono::Duration::hours(1), [&ann, &bob], [&cat, &dan], 520, 400);
+        let second = finished_at(Utc::now(), [&ann, &bob], [&cat, &dan], 400, 520);
+
+        let replayed = Ratings::replay([&second, &first]);
+
+        assert_eq!(replayed, Ratings::default().rate(&first).rate(&second));
+    }
+}
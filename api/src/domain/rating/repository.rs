@@ -0,0 +1,15 @@
+use crate::domain::Ratings;
+
+#[async_trait::async_trait]
+pub trait RatingRepository: Send + Sync {
+    async fn load(&self) -> Result<Ratings, RatingRepositoryError>;
+    /// Saves `ratings` if they are the version last saved, so that two
+    /// updates made from the same ratings cannot overwrite each other.
+    async fn save(&self, ratings: Ratings) -> Result<(), RatingRepositoryError>;
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum RatingRepositoryError {
+    #[error("Ratings were changed meanwhile: expected version {expected}, found {actual}")]
+    Conflict { expected: usize, actual: usize },
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use crate::domain::{Player, PlayerId, Table};
+
+/// A person who plays, known by the same id across games.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PlayerProfile {
+    pub id: PlayerId,
+    pub name: String,
+}
+
+impl PlayerProfile {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { id: PlayerId::new(), name: name.into() }
+    }
+
+    pub fn with_id(mut self, id: PlayerId) -> Self {
+        self.id = id;
+        self
+    }
+}
+
+/// Who sits in `seat` from hand `from_hand` onwards. Hands are numbered from
+/// one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeatAssignment {
+    pub seat: Player,
+    pub player: PlayerProfile,
+    pub from_hand: usize,
+}
+
+/// Seat assignments of a game: the seating it started with and every
+/// substitution since.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Roster {
+    assignments: Vec<SeatAssignment>,
+}
+
+impl Roster {
+    pub fn new(seating: Vec<(Player, PlayerProfile)>) -> Self {
+        Self {
+            assignments: seating
+                .into_iter()
+                .map(|(seat, player)| SeatAssignment { seat, player, from_hand: 1 })
+                .collect(),
+        }
+    }
+
+    pub fn assignments(&self) -> &[SeatAssignment] {
+        &self.assignments
+    }
+
+    /// Assigns a player to a seat from a hand on, replacing whoever was
+    /// already assigned to that seat from the same hand.
+    pub fn with_assignment(&self, assignment: SeatAssignment) -> Self {
+        let mut assignments = self.assignments.clone();
+        match assignments.iter_mut().find(|other| other.seat == assignment.seat && other.from_hand == assignment.from_hand) {
+            Some(existing) => *existing = assignment,
+            None => assignments.push(assignment),
+        }
+        Self { assignments }
+    }
+
+    /// Checks that every assigned seat is at the table and that no seat is
+    /// given to two players from the same hand.
+    pub fn validate(&self, table: &Table) -> Result<(), String> {
+        for (index, assignment) in self.assignments.iter().enumerate() {
+            if !table.seats().contains(&assignment.seat) {
+                return Err(format!("{} is not seated at the table", assignment.seat));
+            }
+            if self.assignments[..index].iter().any(|other| other.seat == assignment.seat && other.from_hand == assignment.from_hand) {
+                return Err(format!("{} is assigned twice from hand {}", assignment.seat, assignment.from_hand));
+            }
+        }
+        Ok(())
+    }
+
+    /// The player sitting in `seat` for hand `hand_number`.
+    pub fn player_at(&self, seat: Player, hand_number: usize) -> Option<&PlayerProfile> {
+        self.assignments
+            .iter()
+            .filter(|assignment| assignment.seat == seat && assignment.from_hand <= hand_number)
+            .max_by_key(|assignment| assignment.from_hand)
+            .map(|assignment| &assignment.player)
+    }
+
+    /// Everyone seated for hand `hand_number`.
+    pub fn seating(&self, hand_number: usize) -> HashMap<Player, PlayerProfile> {
+        self.assignments
+            .iter()
+            .filter_map(|assignment| self.player_at(assignment.seat, hand_number).map(|player| (assignment.seat, player.clone())))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roster() -> Roster {
+        Roster::new(vec![
+            (Player::North, PlayerProfile::new("Ann")),
+            (Player::East, PlayerProfile::new("Bob")),
+            (Player::South, PlayerProfile::new("Cat")),
+            (Player::West, PlayerProfile::new("Dan")),
+        ])
+    }
+
+    #[test]
+    fn should_seat_substitute_from_given_hand() {
+        let eve = PlayerProfile::new("Eve");
+        let roster = roster().with_assignment(SeatAssignment { seat: Player::East, player: eve.clone(), from_hand: 3 });
+
+        assert_eq!(roster.player_at(Player::East, 2).map(|player| player.name.as_str()), Some("Bob"));
+        assert_eq!(roster.player_at(Player::East, 3), Some(&eve));
+        assert_eq!(roster.seating(5).len(), 4);
+        assert_eq!(roster.seating(5)[&Player::East], eve);
+    }
+
+    #[test]
+    fn should_reject_seats_off_the_table_and_double_assignments() {
+        let cutthroat = Table::three_handed_cutthroat(3);
+        let doubled = Roster::new(vec![(Player::North, PlayerProfile::new("Ann")), (Player::North, PlayerProfile::new("Eve"))]);
+
+        assert!(roster().validate(&Table::default()).is_ok());
+        assert!(roster().validate(&cutthroat).is_err());
+        assert!(doubled.validate(&Table::default()).is_err());
+    }
+
+    #[test]
+    fn should_replace_an_assignment_to_the_same_seat_and_hand() {
+        let eve = PlayerProfile::new("Eve");
+        let roster = roster().with_assignment(SeatAssignment { seat: Player::North, player: eve.clone(), from_hand: 1 });
+
+        assert!(roster.validate(&Table::default()).is_ok());
+        assert_eq!(roster.assignments().len(), 4);
+        assert_eq!(roster.player_at(Player::North, 1), Some(&eve));
+    }
+}
@@ -0,0 +1,275 @@
+use serde::{Serialize, Deserialize};
+use crate::domain::{DeckKind, Table};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BidIncrement {
+    pub from: u32,
+    pub step: u32,
+}
+
+/// What happens when nobody bids.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AllPassRule {
+    /// The dealer is forced to take the contract at the minimum bid.
+    #[default]
+    DealerStuck,
+    /// The hand is thrown in and the same dealer deals again.
+    Redeal,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RuleSetPreset {
+    DoubleDeckPartnership500,
+    SingleDeck150,
+    SingleDeckCutthroat,
+    TripleDeckSixHanded,
+    TripleDeckSixHandedThreeTeams,
+    QuadrupleDeckEightHanded,
+}
+
+/// Scoring constants for a game. Every `Hand` of a `Game` is scored with the
+/// rule set the game was created with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RuleSet {
+    pub minimum_bid: u32,
+    /// Increment tiers, ordered by `from`. A bid is valid when it is a whole
+    /// number of `step`s above the `from` of the highest tier it reaches.
+    pub bid_increments: Vec<BidIncrement>,
+    pub minimum_meld: u32,
+    pub minimum_tricks: u32,
+    pub trick_points: u32,
+    /// Points for taking the last trick, included in `trick_points`. The
+    /// counters in the deck make up the rest.
+    #[serde(default)]
+    pub last_trick_bonus: u32,
+    pub game_target: u32,
+    #[serde(default)]
+    pub all_pass: AllPassRule,
+    #[serde(default)]
+    pub deck_kind: DeckKind,
+    #[serde(default)]
+    pub table: Table,
+}
+
+impl RuleSet {
+    pub fn preset(preset: RuleSetPreset) -> Self {
+        match preset {
+            RuleSetPreset::DoubleDeckPartnership500 => Self::double_deck_partnership_500(),
+            RuleSetPreset::SingleDeck150 => Self::single_deck_150(),
+            RuleSetPreset::SingleDeckCutthroat => Self::single_deck_cutthroat(),
+            RuleSetPreset::TripleDeckSixHanded => Self::triple_deck_six_handed(2),
+            RuleSetPreset::TripleDeckSixHandedThreeTeams => Self::triple_deck_six_handed(3),
+            RuleSetPreset::QuadrupleDeckEightHanded => Self::quadruple_deck_eight_handed(),
+        }
+    }
+
+    pub fn double_deck_partnership_500() -> Self {
+        Self {
+            minimum_bid: 50,
+            bid_increments: vec![
+                BidIncrement { from: 50, step: 1 },
+                BidIncrement { from: 60, step: 5 },
+                BidIncrement { from: 100, step: 10 },
+            ],
+            minimum_meld: 20,
+            minimum_tricks: 20,
+            trick_points: 50,
+            last_trick_bonus: 2,
+            game_target: 500,
+            all_pass: AllPassRule::DealerStuck,
+            deck_kind: DeckKind::DoubleDeck,
+            table: Table::four_handed_partnership(),
+        }
+    }
+
+    pub fn single_deck_150() -> Self {
+        Self {
+            minimum_bid: 20,
+            bid_increments: vec![BidIncrement { from: 20, step: 1 }],
+            minimum_meld: 0,
+            minimum_tricks: 1,
+            trick_points: 25,
+            last_trick_bonus: 1,
+            game_target: 150,
+            all_pass: AllPassRule::DealerStuck,
+            deck_kind: DeckKind::SingleDeck,
+            table: Table::four_handed_partnership(),
+        }
+    }
+
+    /// Three-handed single deck: fifteen cards each and a widow of three
+    /// for the bid winner, every player scoring for themselves.
+    pub fn single_deck_cutthroat() -> Self {
+        Self {
+            minimum_bid: 25,
+            table: Table::three_handed_cutthroat(3),
+            ..Self::single_deck_150()
+        }
+    }
+
+    /// Six-handed with a triple deck, as two teams of three or three teams
+    /// of two. Bids, minimums and the target scale with the deck.
+    pub fn triple_deck_six_handed(teams: usize) -> Self {
+        Self::scaled(DeckKind::TripleDeck, Table::six_handed_partnership(teams))
+    }
+
+    /// Eight-handed with a quadruple deck, two teams of four.
+    pub fn quadruple_deck_eight_handed() -> Self {
+        Self::scaled(DeckKind::QuadrupleDeck, Table::eight_handed_partnership())
+    }
+
+    /// The double-deck partnership rules scaled to a larger deck: trick
+    /// points follow the counters in the deck, and bids, minimums and the
+    /// game target grow by half a double deck per extra deck.
+    fn scaled(deck_kind: DeckKind, table: Table) -> Self {
+        let base = Self::double_deck_partnership_500();
+        let scale = |value: u32| value * deck_kind.decks() / 2;
+
+        Self {
+            minimum_bid: scale(base.minimum_bid),
+            bid_increments: base.bid_increments
+                .iter()
+                .map(|increment| BidIncrement { from: scale(increment.from), step: increment.step })
+                .collect(),
+            minimum_meld: scale(base.minimum_meld),
+            minimum_tricks: scale(base.minimum_tricks),
+            trick_points: deck_kind.counters() + base.last_trick_bonus,
+            game_target: scale(base.game_target),
+            deck_kind,
+            table,
+            ..base
+        }
+    }
+
+    /// Checks that a rule set can be played: no zero minimums, targets or
+    /// increments, a minimum bid the increments accept, and trick points
+    /// that match the counters in the deck.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.minimum_bid == 0 {
+            return Err("The minimum bid must be above zero".to_string());
+        }
+        if self.game_target == 0 {
+            return Err("The game target must be above zero".to_string());
+        }
+        if self.bid_increments.is_empty() || self.bid_increments.iter().any(|increment| increment.step == 0) {
+            return Err("Bid increments need at least one tier and no zero steps".to_string());
+        }
+        if !self.is_valid_bid(self.minimum_bid) {
+            return Err(format!("The minimum bid of {} is not a valid bid under the increments", self.minimum_bid));
+        }
+        if self.last_trick_bonus >= self.trick_points {
+            return Err(format!("The last trick bonus of {} must be less than the {} trick points", self.last_trick_bonus, self.trick_points));
+        }
+        if self.counters() != self.deck_kind.counters() {
+            return Err(format!(
+                "Trick points of {} less a last trick bonus of {} do not match the {} counters in a {:?}",
+                self.trick_points, self.last_trick_bonus, self.deck_kind.counters(), self.deck_kind
+            ));
+        }
+        if self.minimum_tricks > self.trick_points {
+            return Err(format!("The minimum tricks of {} are more than the {} trick points", self.minimum_tricks, self.trick_points));
+        }
+        Ok(())
+    }
+
+    pub fn is_valid_bid(&self, amount: u32) -> bool {
+        if amount < self.minimum_bid {
+            return false;
+        }
+
+        self.bid_increments
+            .iter()
+            .filter(|increment| amount >= increment.from)
+            .max_by_key(|increment| increment.from)
+            .map(|increment| increment.step > 0 && (amount - increment.from).is_multiple_of(increment.step))
+            .unwrap_or(false)
+    }
+
+    /// Counters (aces, tens and kings) in the deck.
+    pub fn counters(&self) -> u32 {
+        self.trick_points.saturating_sub(self.last_trick_bonus)
+    }
+
+    pub fn has_reached_target(&self, total: i32) -> bool {
+        total >= self.game_target as i32
+    }
+}
+
+impl Default for RuleSet {
+    fn default() -> Self {
+        RuleSet::double_deck_partnership_500()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_rule_set_should_be_double_deck_partnership_500() {
+        assert_eq!(RuleSet::default(), RuleSet::preset(RuleSetPreset::DoubleDeckPartnership500));
+    }
+
+    #[test]
+    fn double_deck_should_follow_increment_tiers() {
+        let rules = RuleSet::double_deck_partnership_500();
+
+        assert!(!rules.is_valid_bid(49));
+        assert!(rules.is_valid_bid(50));
+        assert!(rules.is_valid_bid(59));
+        assert!(rules.is_valid_bid(65));
+        assert!(!rules.is_valid_bid(61));
+        assert!(rules.is_valid_bid(110));
+        assert!(!rules.is_valid_bid(105));
+    }
+
+    #[test]
+    fn single_deck_should_accept_any_bid_from_minimum() {
+        let rules = RuleSet::single_deck_150();
+
+        assert!(!rules.is_valid_bid(19));
+        assert!(rules.is_valid_bid(20));
+        assert!(rules.is_valid_bid(27));
+        assert!(rules.has_reached_target(150));
+        assert_eq!(rules.counters(), 24);
+        assert!(!rules.has_reached_target(149));
+    }
+
+    #[test]
+    fn larger_decks_should_scale_trick_points_and_bids() {
+        let six = RuleSet::preset(RuleSetPreset::TripleDeckSixHanded);
+        let eight = RuleSet::preset(RuleSetPreset::QuadrupleDeckEightHanded);
+
+        assert_eq!(six.trick_points, 74);
+        assert_eq!(six.counters(), 72);
+        assert_eq!(six.minimum_bid, 75);
+        assert!(six.is_valid_bid(95));
+        assert!(!six.is_valid_bid(92));
+        assert_eq!(eight.trick_points, 98);
+        assert_eq!(eight.game_target, 1000);
+        assert_eq!(eight.table.seats().len(), 8);
+    }
+
+    #[test]
+    fn presets_should_be_valid_and_zero_or_inconsistent_values_rejected() {
+        for preset in [
+            RuleSetPreset::DoubleDeckPartnership500,
+            RuleSetPreset::SingleDeck150,
+            RuleSetPreset::SingleDeckCutthroat,
+            RuleSetPreset::TripleDeckSixHanded,
+            RuleSetPreset::TripleDeckSixHandedThreeTeams,
+            RuleSetPreset::QuadrupleDeckEightHanded,
+        ] {
+            assert_eq!(RuleSet::preset(preset).validate(), Ok(()));
+        }
+
+        let rules = RuleSet::default();
+        assert!(RuleSet { game_target: 0, ..rules.clone() }.validate().is_err());
+        assert!(RuleSet { minimum_bid: 0, ..rules.clone() }.validate().is_err());
+        assert!(RuleSet { minimum_bid: 45, ..rules.clone() }.validate().is_err());
+        assert!(RuleSet { bid_increments: vec![BidIncrement { from: 50, step: 0 }], ..rules.clone() }.validate().is_err());
+        assert!(RuleSet { trick_points: 40, ..rules.clone() }.validate().is_err());
+        assert!(RuleSet { deck_kind: DeckKind::SingleDeck, ..rules.clone() }.validate().is_err());
+        assert!(RuleSet { minimum_tricks: 60, ..rules }.validate().is_err());
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::{HashMap, HashSet};
+use crate::domain::{Game, Hand, Player, PlayerId, PlayerProfile, Suit, Winner};
+
+/// Career numbers of a seat or of a person, tallied from scored hands.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PlayerStats {
+    pub hands_played: u32,
+    pub bids_won: u32,
+    pub times_set: u32,
+    pub total_bid: u32,
+    pub total_meld: u32,
+    pub no_marriage: u32,
+    pub games_played: u32,
+    pub games_won: u32,
+    /// How often each suit was named trump on a won bid.
+    pub trumps: HashMap<Suit, u32>,
+}
+
+impl PlayerStats {
+    /// Share of won bids that were made rather than set.
+    pub fn bid_success_rate(&self) -> Option<f64> {
+        (self.bids_won > 0).then(|| (self.bids_won - self.times_set) as f64 / self.bids_won as f64)
+    }
+
+    pub fn average_bid(&self) -> Option<f64> {
+        (self.bids_won > 0).then(|| self.total_bid as f64 / self.bids_won as f64)
+    }
+
+    pub fn average_meld(&self) -> Option<f64> {
+        (self.hands_played > 0).then(|| self.total_meld as f64 / self.hands_played as f64)
+    }
+
+    /// The suit named trump most often. Ties go to the suit listed first.
+    pub fn favorite_trump(&self) -> Option<Suit> {
+        Suit::PLAYABLE
+            .iter()
+            .rev()
+            .filter(|suit| self.trumps.get(suit).is_some_and(|count| *count > 0))
+            .max_by_key(|suit| self.trumps[suit])
+            .copied()
+    }
+
+    fn record_hand(&mut self, hand: &Hand, seat: Player) {
+        self.hands_played += 1;
+        self.total_meld += hand.meld_of(seat);
+
+        if hand.bidder() != Some(seat) {
+            return;
+        }
+
+        self.bids_won += 1;
+        self.total_bid += hand.bid_amount().unwrap_or(0);
+        if hand.was_set() == Some(true) {
+            self.times_set += 1;
+        }
+        match hand.trump() {
+            Some(Suit::NoMarriage) => self.no_marriage += 1,
+            Some(trump) => *self.trumps.entry(trump).or_default() += 1,
+            None => {}
+        }
+    }
+}
+
+/// Stats of every seat, and of every person a roster put in a seat, over a
+/// set of games.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatsReport {
+    pub by_seat: HashMap<Player, PlayerStats>,
+    pub by_player: HashMap<PlayerId, PlayerStats>,
+    pub players: HashMap<PlayerId, PlayerProfile>,
+}
+
+impl StatsReport {
+    pub fn tally<'a>(games: impl IntoIterator<Item = &'a Game>) -> Self {
+        let mut report = Self::default();
+        for game in games {
+            report.add_game(game);
+        }
+        report
+    }
+
+    fn add_game(&mut self, game: &Game) {
+        let hands = game.scored_hands();
+        let Some(last) = hands.last() else {
+            return;
+        };
+
+        let table = &game.rule_set().table;
+        let mut played: HashSet<PlayerId> = HashSet::new();
+        for hand in &hands {
+            for seat in table.seats() {
+                self.by_seat.entry(*seat).or_default().record_hand(hand, *seat);
+                if let Some(profile) = hand.players().get(seat) {
+                    self.by_player.entry(profile.id).or_default().record_hand(hand, *seat);
+                    self.players.insert(profile.id, profile.clone());
+                    played.insert(profile.id);
+                }
+            }
+        }
+
+        let winners: &[Player] = match game.result().map(|result| result.winner) {
+            Some(Winner::Team(team)) => table.members(team),
+            Some(Winner::Player(player)) => &[player],
+            None => &[],
+        };
+
+        for seat in table.seats() {
+            let stats = self.by_seat.entry(*seat).or_default();
+            stats.games_played += 1;
+            if winners.contains(seat) {
+                stats.games_won += 1;
+            }
+        }
+
+        for id in &played {
+            self.by_player.entry(*id).or_default().games_played += 1;
+        }
+        for seat in winners {
+            if let Some(profile) = last.players().get(seat) {
+                self.by_player.entry(profile.id).or_default().games_won += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{HandScore, HandState, PlayerProfile};
+
+    fn completed(bidder: Player, trump: Suit, us_total: i32, them_total: i32) -> Hand {
+        Hand::new(Player::South).set_state(
+            HandState::Completed { bidder, bid_amount: 60, trump },
+            HandScore::us_them((20, 10), (30, 20), (us_total, them_total)),
+        )
+    }
+
+    #[test]
+    fn should_tally_bids_sets_and_trump_per_seat() {
+        let game = Game::new(Player::South).with_completed_hands(vec![
+            completed(Player::North, Suit::Hearts, 50, 30),
+            completed(Player::North, Suit::Hearts, -60, 30),
+            completed(Player::North, Suit::NoMarriage, -60, 10),
+            completed(Player::East, Suit::Spades, 50, 30),
+        ]);
+
+        let report = StatsReport::tally([&game]);
+        let north = &report.by_seat[&Player::North];
+
+        assert_eq!(north.hands_played, 4);
+        assert_eq!(north.bids_won, 3);
+        assert_eq!(north.times_set, 2);
+        assert_eq!(north.no_marriage, 1);
+        assert_eq!(north.average_bid(), Some(60.0));
+        assert_eq!(north.average_meld(), Some(20.0));
+        assert_eq!(north.favorite_trump(), Some(Suit::Hearts));
+        assert_eq!(north.games_played, 1);
+        assert_eq!(report.by_seat[&Player::East].bid_success_rate(), Some(1.0));
+    }
+
+    #[test]
+    fn should_follow_people_across_seats() {
+        let alice = PlayerProfile::new("Alice");
+        let first = completed(Player::North, Suit::Clubs, 50, 30)
+            .with_players(HashMap::from([(Player::North, alice.clone())]));
+        let second = completed(Player::East, Suit::Clubs, 50, 30)
+            .with_players(HashMap::from([(Player::East, alice.clone())]));
+
+        let report = StatsReport::tally([
+            &Game::new(Player::South).with_completed_hands(vec![first]),
+            &Game::new(Player::South).with_completed_hands(vec![second]),
+        ]);
+        let stats = &report.by_player[&alice.id];
+
+        assert_eq!(stats.hands_played, 2);
+        assert_eq!(stats.bids_won, 2);
+        assert_eq!(stats.games_played, 2);
+        assert_eq!(report.players[&alice.id].name, "Alice");
+    }
+}
@@ -0,0 +1,277 @@
+use std::collections::HashSet;
+use serde::{Serialize, Deserialize};
+use crate::domain::{Player, Team, TeamId};
+
+/// A team at the table: its id, the name shown for it and the seats of its
+/// members. A partnership of one is a player scoring for themselves.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Partnership {
+    pub id: TeamId,
+    pub name: String,
+    pub seats: Vec<Player>,
+}
+
+impl Partnership {
+    pub fn new(id: TeamId, name: impl Into<String>, seats: Vec<Player>) -> Self {
+        Self { id, name: name.into(), seats }
+    }
+}
+
+/// Who sits at the table and who plays with whom. Seats are listed in
+/// clockwise order. At a two-team table the first partnership is reported
+/// as "Us" and the second as "Them".
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Table {
+    pub seats: Vec<Player>,
+    pub teams: Vec<Partnership>,
+    /// Cards dealt face down to the widow (kitty) and taken by the bid winner.
+    #[serde(default)]
+    pub widow: usize,
+}
+
+impl Table {
+    /// Seats `seats` clockwise and deals them into `teams` partnerships,
+    /// so that partners never sit next to each other.
+    pub fn partnership(seats: Vec<Player>, teams: usize) -> Self {
+        let teams = (0..teams)
+            .map(|team| Partnership::new(
+                TeamId(team),
+                format!("Team {}", team + 1),
+                seats.iter().copied().skip(team).step_by(teams).collect(),
+            ))
+            .collect();
+        Self { seats, teams, widow: 0 }
+    }
+
+    /// North/South ("Us") against East/West ("Them"), seated and sided as
+    /// `Player::next_clockwise` and `Player::team` describe.
+    pub fn four_handed_partnership() -> Self {
+        let seats: Vec<Player> = std::iter::successors(Some(Player::North), |seat| Some(seat.next_clockwise()))
+            .take(4)
+            .collect();
+        let teams = [Team::Us, Team::Them]
+            .into_iter()
+            .enumerate()
+            .map(|(id, team)| Partnership::new(
+                TeamId(id),
+                format!("Team {}", id + 1),
+                seats.iter().copied().filter(|seat| seat.team() == team).collect(),
+            ))
+            .collect();
+        Self { seats, teams, widow: 0 }.with_team_names(["Us", "Them"])
+    }
+
+    /// Six players as two teams of three or three teams of two.
+    pub fn six_handed_partnership(teams: usize) -> Self {
+        Self::partnership(vec![
+            Player::North, Player::NorthEast, Player::SouthEast,
+            Player::South, Player::SouthWest, Player::NorthWest,
+        ], teams)
+    }
+
+    /// Eight players as two teams of four.
+    pub fn eight_handed_partnership() -> Self {
+        Self::partnership(vec![
+            Player::North, Player::NorthEast, Player::East, Player::SouthEast,
+            Player::South, Player::SouthWest, Player::West, Player::NorthWest,
+        ], 2)
+    }
+
+    /// Three players, each scoring for themselves, with a widow for the bid
+    /// winner.
+    pub fn three_handed_cutthroat(widow: usize) -> Self {
+        let seats = vec![Player::North, Player::East, Player::South];
+        let teams = seats.iter()
+            .enumerate()
+            .map(|(team, player)| Partnership::new(TeamId(team), player.to_string(), vec![*player]))
+            .collect();
+        Self { seats, teams, widow }
+    }
+
+    /// Renames the partnerships in order.
+    pub fn with_team_names<const N: usize>(mut self, names: [&str; N]) -> Self {
+        for (team, name) in self.teams.iter_mut().zip(names) {
+            team.name = name.to_string();
+        }
+        self
+    }
+
+    /// Replaces the partnerships, keeping the seats.
+    pub fn with_partnerships(self, teams: Vec<Partnership>) -> Self {
+        Self { teams, ..self }
+    }
+
+    /// Checks that every seat plays for exactly one team and that team ids
+    /// are unique.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.seats.is_empty() || self.teams.is_empty() {
+            return Err("A table needs seats and teams".to_string());
+        }
+
+        let mut ids = HashSet::new();
+        if let Some(team) = self.teams.iter().find(|team| !ids.insert(team.id)) {
+            return Err(format!("Team id {} is used twice", team.id.0));
+        }
+
+        let mut seated = HashSet::new();
+        for seat in self.teams.iter().flat_map(|team| team.seats.iter()) {
+            if !self.seats.contains(seat) {
+                return Err(format!("{} is not seated at the table", seat));
+            }
+            if !seated.insert(*seat) {
+                return Err(format!("{} plays for more than one team", seat));
+            }
+        }
+
+        match self.seats.iter().find(|seat| !seated.contains(*seat)) {
+            Some(seat) => Err(format!("{} does not play for any team", seat)),
+            None => Ok(()),
+        }
+    }
+
+    pub fn seats(&self) -> &[Player] {
+        &self.seats
+    }
+
+    pub fn widow(&self) -> usize {
+        self.widow
+    }
+
+    pub fn teams(&self) -> &[Partnership] {
+        &self.teams
+    }
+
+    pub fn team_ids(&self) -> impl Iterator<Item = TeamId> + '_ {
+        self.teams.iter().map(|team| team.id)
+    }
+
+    pub fn members(&self, team: TeamId) -> &[Player] {
+        self.teams.iter()
+            .find(|partnership| partnership.id == team)
+            .map(|partnership| partnership.seats.as_slice())
+            .unwrap_or_default()
+    }
+
+    pub fn team_name(&self, team: TeamId) -> Option<&str> {
+        self.teams.iter()
+            .find(|partnership| partnership.id == team)
+            .map(|partnership| partnership.name.as_str())
+    }
+
+    /// The team `player` plays for, or `None` when they are not seated.
+    pub fn team_of(&self, player: Player) -> Option<TeamId> {
+        self.teams
+            .iter()
+            .find(|team| team.seats.contains(&player))
+            .map(|team| team.id)
+    }
+
+    /// The ids reported as "Us" and "Them", at a two-team table, the shape
+    /// the team-total API uses.
+    pub fn us_them(&self) -> Option<(TeamId, TeamId)> {
+        match self.teams.as_slice() {
+            [us, them] => Some((us.id, them.id)),
+            _ => None,
+        }
+    }
+
+    /// The team reported as `team` at a two-team table.
+    pub fn team_id(&self, team: Team) -> Option<TeamId> {
+        self.us_them().map(|(us, them)| match team {
+            Team::Us => us,
+            Team::Them => them,
+        })
+    }
+
+    /// Whether every player scores for themselves.
+    pub fn is_individual(&self) -> bool {
+        self.teams.iter().all(|team| team.seats.len() == 1)
+    }
+
+    /// The next seat clockwise from `player`, or `None` when they are not
+    /// seated.
+    pub fn next_clockwise(&self, player: Player) -> Option<Player> {
+        self.seats
+            .iter()
+            .position(|seat| *seat == player)
+            .map(|index| self.seats[(index + 1) % self.seats.len()])
+    }
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Table::four_handed_partnership()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn four_handed_table_should_seat_north_south_against_east_west() {
+        let table = Table::default();
+
+        assert_eq!(table.next_clockwise(Player::North), Some(Player::East));
+        assert_eq!(table.next_clockwise(Player::East), Some(Player::South));
+        assert_eq!(table.next_clockwise(Player::South), Some(Player::West));
+        assert_eq!(table.next_clockwise(Player::West), Some(Player::North));
+        assert_eq!(table.team_of(Player::North), Some(TeamId::US));
+        assert_eq!(table.team_of(Player::South), Some(TeamId::US));
+        assert_eq!(table.team_of(Player::East), Some(TeamId::THEM));
+        assert_eq!(table.team_of(Player::West), Some(TeamId::THEM));
+        assert_eq!(table.team_of(Player::NorthEast), None);
+        assert_eq!(table.next_clockwise(Player::NorthEast), None);
+        assert!(table.us_them().is_some());
+        assert_eq!(table.team_name(TeamId::US), Some("Us"));
+        assert_eq!(table.team_id(Team::Them), Some(TeamId::THEM));
+    }
+
+    #[test]
+    fn cutthroat_table_should_seat_three_individuals() {
+        let table = Table::three_handed_cutthroat(3);
+
+        assert_eq!(table.next_clockwise(Player::South), Some(Player::North));
+        assert_eq!(table.team_of(Player::East), Some(TeamId(1)));
+        assert_eq!(table.members(TeamId(2)), &[Player::South]);
+        assert!(table.is_individual());
+        assert!(table.us_them().is_none());
+    }
+
+    #[test]
+    fn six_handed_tables_should_seat_partners_apart() {
+        let two_teams = Table::six_handed_partnership(2);
+        let three_teams = Table::six_handed_partnership(3);
+
+        assert_eq!(two_teams.members(TeamId(0)), &[Player::North, Player::SouthEast, Player::SouthWest]);
+        assert_eq!(three_teams.members(TeamId(1)), &[Player::NorthEast, Player::SouthWest]);
+        assert_eq!(three_teams.next_clockwise(Player::NorthWest), Some(Player::North));
+    }
+
+    #[test]
+    fn eight_handed_table_should_alternate_teams() {
+        let table = Table::eight_handed_partnership();
+
+        assert_eq!(table.members(TeamId(1)).len(), 4);
+        assert_ne!(table.team_of(Player::North), table.team_of(Player::NorthEast));
+        assert!(table.us_them().is_some());
+    }
+
+    #[test]
+    fn should_validate_custom_partnerships() {
+        let table = Table::default();
+        let renamed = table.clone().with_partnerships(vec![
+            Partnership::new(TeamId(7), "Smiths", vec![Player::North, Player::South]),
+            Partnership::new(TeamId(3), "Joneses", vec![Player::East, Player::West]),
+        ]);
+        let overlapping = table.clone().with_partnerships(vec![
+            Partnership::new(TeamId(0), "Smiths", vec![Player::North, Player::South]),
+            Partnership::new(TeamId(1), "Joneses", vec![Player::South, Player::West]),
+        ]);
+
+        assert!(renamed.validate().is_ok());
+        assert_eq!(renamed.us_them(), Some((TeamId(7), TeamId(3))));
+        assert_eq!(renamed.team_of(Player::East), Some(TeamId(3)));
+        assert!(overlapping.validate().is_err());
+    }
+}
@@ -0,0 +1,7 @@
+pub use tournament_error::TournamentError;
+pub use schedule::{Round, Standing, TableAssignment, Tournament};
+pub use repository::{TournamentRepository, TournamentRepositoryError};
+
+pub mod tournament_error;
+pub mod schedule;
+pub mod repository;
@@ -0,0 +1,13 @@
+use crate::domain::{Tournament, TournamentId};
+
+#[async_trait::async_trait]
+pub trait TournamentRepository: Send + Sync {
+    async fn find_by_id(&self, id: TournamentId) -> Result<Option<Tournament>, TournamentRepositoryError>;
+    async fn save(&self, tournament: Tournament) -> Result<(), TournamentRepositoryError>;
+}
+
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum TournamentRepositoryError {
+    #[error("Tournament isn't found: {0}")]
+    TournamentDoesNotExist(TournamentId),
+}
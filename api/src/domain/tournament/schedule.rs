@@ -0,0 +1,254 @@
+use std::collections::{HashMap, HashSet};
+use crate::domain::{Game, GameId, PlayerId, PlayerProfile, Roster, RuleSet, Team, TournamentError, TournamentId};
+
+/// One game of a round: the partnership seated as "Us", the one seated as
+/// "Them", and the game they play.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableAssignment {
+    pub table: usize,
+    pub us: Vec<PlayerProfile>,
+    pub them: Vec<PlayerProfile>,
+    pub game_id: GameId,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub number: usize,
+    pub tables: Vec<TableAssignment>,
+}
+
+/// A player's place in the tournament. The point differential adds up the
+/// player's team total less the opponents' over completed games.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Standing {
+    pub player: PlayerProfile,
+    pub games_played: u32,
+    pub games_won: u32,
+    pub point_differential: i32,
+}
+
+/// An individual round robin of four-handed partnership games. Partners
+/// rotate so that over a full tournament every player partners every other
+/// player once; the partnership seated North/South and the first dealer
+/// rotate from round to round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tournament {
+    id: TournamentId,
+    name: String,
+    players: Vec<PlayerProfile>,
+    rule_set: RuleSet,
+    rounds: Vec<Round>,
+}
+
+impl Tournament {
+    /// Schedules the tournament and creates the games of every round, which
+    /// are returned to be saved. `rounds` caps the number of rounds, which
+    /// is one less than the number of players for a full round robin.
+    pub fn new(name: impl Into<String>, players: Vec<PlayerProfile>, rule_set: RuleSet, rounds: Option<usize>) -> Result<(Self, Vec<Game>), TournamentError> {
+        if players.len() < 4 || !players.len().is_multiple_of(4) {
+            return Err(TournamentError::InvalidPlayerCount(players.len()));
+        }
+        let mut entered = HashSet::new();
+        if let Some(player) = players.iter().find(|player| !entered.insert(player.id)) {
+            return Err(TournamentError::DuplicatePlayer(player.name.clone()));
+        }
+
+        rule_set.validate().map_err(TournamentError::InvalidRuleSet)?;
+        let table = &rule_set.table;
+        table.validate().map_err(TournamentError::UnsupportedTable)?;
+        let (Some(us), Some(them)) = (table.team_id(Team::Us), table.team_id(Team::Them)) else {
+            return Err(TournamentError::UnsupportedTable("Tournaments are played by two partnerships".to_string()));
+        };
+        if table.seats().len() != 4 || table.members(us).len() != 2 || table.members(them).len() != 2 {
+            return Err(TournamentError::UnsupportedTable("Tournaments are played four-handed".to_string()));
+        }
+
+        let full = players.len() - 1;
+        let round_count = rounds.unwrap_or(full).clamp(1, full);
+
+        let mut games = Vec::new();
+        let mut scheduled = Vec::new();
+        for (index, pairs) in Self::partner_rotation(players.len()).into_iter().take(round_count).enumerate() {
+            let dealer = table.seats()[index % table.seats().len()];
+            let mut tables = Vec::new();
+            for (number, matchup) in pairs.chunks(2).enumerate() {
+                let (first, second) = (matchup[0], matchup[1]);
+                let (north_south, east_west) = if index % 2 == 0 { (first, second) } else { (second, first) };
+                let side = |(a, b): (usize, usize)| vec![players[a].clone(), players[b].clone()];
+
+                let seating: Vec<_> = table.members(us).iter().copied().zip(side(north_south))
+                    .chain(table.members(them).iter().copied().zip(side(east_west)))
+                    .collect();
+                let roster = Roster::new(seating);
+                roster.validate(table).map_err(TournamentError::InvalidSeating)?;
+
+                let game = Game::new(dealer).with_rule_set(rule_set.clone()).with_roster(roster);
+                tables.push(TableAssignment {
+                    table: number + 1,
+                    us: side(north_south),
+                    them: side(east_west),
+                    game_id: game.id(),
+                });
+                games.push(game);
+            }
+            scheduled.push(Round { number: index + 1, tables });
+        }
+
+        Ok((
+            Self {
+                id: TournamentId::new(),
+                name: name.into(),
+                players,
+                rule_set,
+                rounds: scheduled,
+            },
+            games,
+        ))
+    }
+
+    /// Partnerships for each round by the circle method: the last player
+    /// stays put while the others rotate, so every pair meets exactly once
+    /// over `players - 1` rounds.
+    fn partner_rotation(players: usize) -> Vec<Vec<(usize, usize)>> {
+        let rotating = players - 1;
+        (0..rotating)
+            .map(|round| {
+                std::iter::once((round, rotating))
+                    .chain((1..players / 2).map(|k| ((round + k) % rotating, (round + rotating - k) % rotating)))
+                    .collect()
+            })
+            .collect()
+    }
+
+    pub fn id(&self) -> TournamentId {
+        self.id
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn players(&self) -> &[PlayerProfile] {
+        &self.players
+    }
+
+    pub fn rule_set(&self) -> &RuleSet {
+        &self.rule_set
+    }
+
+    pub fn rounds(&self) -> &[Round] {
+        &self.rounds
+    }
+
+    pub fn game_ids(&self) -> impl Iterator<Item = GameId> + '_ {
+        self.rounds.iter().flat_map(|round| round.tables.iter().map(|table| table.game_id))
+    }
+
+    /// Standings from the tournament's completed games, ranked by games won
+    /// and then by point differential.
+    pub fn standings(&self, games: &HashMap<GameId, Game>) -> Vec<Standing> {
+        let mut standings: HashMap<PlayerId, Standing> = self.players.iter()
+            .map(|player| (player.id, Standing { player: player.clone(), games_played: 0, games_won: 0, point_differential: 0 }))
+            .collect();
+
+        for assignment in self.rounds.iter().flat_map(|round| round.tables.iter()) {
+            let Some(game) = games.get(&assignment.game_id) else {
+                continue;
+            };
+            let Some(winner) = game.winning_team() else {
+                continue;
+            };
+            let (us, them) = game.running_totals();
+            let us_won = game.rule_set().table.team_id(Team::Us) == Some(winner);
+
+            for (side, differential, won) in [(&assignment.us, us - them, us_won), (&assignment.them, them - us, !us_won)] {
+                for player in side {
+                    if let Some(standing) = standings.get_mut(&player.id) {
+                        standing.games_played += 1;
+                        standing.games_won += won as u32;
+                        standing.point_differential += differential;
+                    }
+                }
+            }
+        }
+
+        let mut standings: Vec<Standing> = standings.into_values().collect();
+        standings.sort_by(|a, b| {
+            b.games_won.cmp(&a.games_won)
+                .then(b.point_differential.cmp(&a.point_differential))
+                .then_with(|| a.player.name.cmp(&b.player.name))
+        });
+        standings
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use crate::domain::{Player, TeamId, Winner};
+
+    fn players(count: usize) -> Vec<PlayerProfile> {
+        (1..=count).map(|number| PlayerProfile::new(format!("Player {:02}", number))).collect()
+    }
+
+    #[test]
+    fn should_reject_counts_that_do_not_fill_tables() {
+        assert_eq!(Tournament::new("Spring", players(6), RuleSet::default(), None).unwrap_err(), TournamentError::InvalidPlayerCount(6));
+        assert!(matches!(
+            Tournament::new("Spring", players(4), RuleSet::preset(crate::domain::RuleSetPreset::SingleDeckCutthroat), None),
+            Err(TournamentError::UnsupportedTable(_))
+        ));
+    }
+
+    #[test]
+    fn should_rotate_partners_so_everyone_partners_everyone_once() {
+        let entrants = players(8);
+        let (tournament, games) = Tournament::new("Spring", entrants.clone(), RuleSet::default(), None).unwrap();
+
+        assert_eq!(tournament.rounds().len(), 7);
+        assert_eq!(games.len(), 14);
+
+        let mut partners = HashSet::new();
+        for round in tournament.rounds() {
+            let seated: HashSet<PlayerId> = round.tables.iter()
+                .flat_map(|table| table.us.iter().chain(table.them.iter()))
+                .map(|player| player.id)
+                .collect();
+            assert_eq!(seated.len(), 8);
+
+            for side in round.tables.iter().flat_map(|table| [&table.us, &table.them]) {
+                let mut pair = [side[0].id.0, side[1].id.0];
+                pair.sort();
+                assert!(partners.insert(pair));
+            }
+        }
+        assert_eq!(partners.len(), 8 * 7 / 2);
+        assert_eq!(games[0].roster().player_at(Player::North, 1), Some(&tournament.rounds()[0].tables[0].us[0]));
+        assert_eq!(games[2].current_dealer(), Player::East);
+    }
+
+    #[test]
+    fn should_rank_by_wins_then_point_differential() {
+        let (tournament, games) = Tournament::new("Spring", players(4), RuleSet::default(), Some(2)).unwrap();
+        let [first, second] = [&games[0], &games[1]];
+
+        let finished: HashMap<GameId, Game> = [
+            first.add_completed_hand_with_scores(520, 300, Player::North).with_winner(Winner::Team(TeamId::US), Utc::now()),
+            second.add_completed_hand_with_scores(500, 480, Player::East).with_winner(Winner::Team(TeamId::US), Utc::now()),
+        ]
+            .into_iter()
+            .map(|game| (game.id(), game))
+            .collect();
+
+        let standings = tournament.standings(&finished);
+        let round_one = &tournament.rounds()[0].tables[0];
+        let round_two = &tournament.rounds()[1].tables[0];
+        let twice = round_one.us.iter().find(|player| round_two.us.contains(player)).unwrap();
+
+        assert_eq!(standings[0].player, *twice);
+        assert_eq!((standings[0].games_won, standings[0].point_differential), (2, 240));
+        assert_eq!(standings[1].point_differential, 220 - 20);
+        assert_eq!(standings[3].games_won, 0);
+    }
+}
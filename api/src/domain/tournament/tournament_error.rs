@@ -0,0 +1,13 @@
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum TournamentError {
+    #[error("A tournament needs a multiple of four players, at least four, not {0}")]
+    InvalidPlayerCount(usize),
+    #[error("{0} is entered twice")]
+    DuplicatePlayer(String),
+    #[error("Invalid rule set: {0}")]
+    InvalidRuleSet(String),
+    #[error("Unsupported table: {0}")]
+    UnsupportedTable(String),
+    #[error("Invalid seating: {0}")]
+    InvalidSeating(String),
+}
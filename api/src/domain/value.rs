@@ -2,13 +2,47 @@ use std::fmt;
 use uuid::Uuid;
 use serde::{Serialize, Deserialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Team {
     Us,
     Them,
 }
 
+/// A side at the table: a partnership, or a single player when everyone
+/// scores for themselves. In a four-handed partnership game team 0 is "Us"
+/// and team 1 is "Them".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct TeamId(pub usize);
+
+impl TeamId {
+    pub const US: TeamId = TeamId(0);
+    pub const THEM: TeamId = TeamId(1);
+}
+
+impl From<Team> for TeamId {
+    fn from(team: Team) -> Self {
+        match team {
+            Team::Us => TeamId::US,
+            Team::Them => TeamId::THEM,
+        }
+    }
+}
+
+impl fmt::Display for TeamId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Team {}", self.0)
+    }
+}
+
+/// Who won a game: a partnership, or a player when everyone scores for
+/// themselves.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Winner {
+    Team(TeamId),
+    Player(Player),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Suit {
     Spades,
     Hearts,
@@ -17,6 +51,10 @@ pub enum Suit {
     NoMarriage
 }
 
+impl Suit {
+    pub const PLAYABLE: [Suit; 4] = [Suit::Spades, Suit::Hearts, Suit::Clubs, Suit::Diamonds];
+}
+
 impl fmt::Display for Suit {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
@@ -29,28 +67,127 @@ impl fmt::Display for Suit {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+/// Card ranks in pinochle order, lowest to highest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Rank {
+    Nine,
+    Jack,
+    Queen,
+    King,
+    Ten,
+    Ace,
+}
+
+impl fmt::Display for Rank {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Rank::Nine => write!(f, "Nine"),
+            Rank::Jack => write!(f, "Jack"),
+            Rank::Queen => write!(f, "Queen"),
+            Rank::King => write!(f, "King"),
+            Rank::Ten => write!(f, "Ten"),
+            Rank::Ace => write!(f, "Ace"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Card {
+    pub suit: Suit,
+    pub rank: Rank,
+}
+
+impl Card {
+    pub fn new(rank: Rank, suit: Suit) -> Self {
+        Self { suit, rank }
+    }
+
+    /// Aces, tens and kings count one point each in the tricks they win.
+    pub fn is_counter(self) -> bool {
+        matches!(self.rank, Rank::Ace | Rank::Ten | Rank::King)
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} of {}", self.rank, self.suit)
+    }
+}
+
+/// Single-deck pinochle plays with 48 cards, nines included. Double-deck
+/// plays with 80 cards and no nines; triple and quadruple decks add another
+/// 40 cards per deck for six- and eight-handed games.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[allow(clippy::enum_variant_names)]
+pub enum DeckKind {
+    SingleDeck,
+    #[default]
+    DoubleDeck,
+    TripleDeck,
+    QuadrupleDeck,
+}
+
+impl DeckKind {
+    pub fn has_nines(self) -> bool {
+        matches!(self, DeckKind::SingleDeck)
+    }
+
+    /// Copies of each card in the deck.
+    pub fn copies(self) -> u32 {
+        match self {
+            DeckKind::SingleDeck => 2,
+            DeckKind::DoubleDeck => 4,
+            DeckKind::TripleDeck => 6,
+            DeckKind::QuadrupleDeck => 8,
+        }
+    }
+
+    /// Number of double decks shuffled together; a single deck counts as one.
+    pub fn decks(self) -> u32 {
+        std::cmp::max(self.copies() / 2, 1)
+    }
+
+    /// Counters (aces, tens and kings) in the deck, one point each.
+    pub fn counters(self) -> u32 {
+        3 * Suit::PLAYABLE.len() as u32 * self.copies()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Player {
     North,
     South,
     East,
     West,
+    NorthEast,
+    SouthEast,
+    SouthWest,
+    NorthWest,
 }
 
 impl Player {
+    /// The player's side at a four-handed table. Seats between the compass
+    /// points are only used at larger tables, whose `Table` defines the
+    /// partnerships.
     pub fn team(self) -> Team {
         match self {
-            Player::North | Player::South => Team::Us,
-            Player::East | Player::West => Team::Them,
+            Player::North | Player::South | Player::NorthEast | Player::SouthWest => Team::Us,
+            Player::East | Player::West | Player::SouthEast | Player::NorthWest => Team::Them,
         }
     }
 
+    /// The next seat at a four-handed table. Seats between the compass
+    /// points move on to the next compass point.
     pub fn next_clockwise(self) -> Player {
         match self {
             Player::South => Player::West,
             Player::West => Player::North,
             Player::North => Player::East,
             Player::East => Player::South,
+            Player::NorthEast => Player::East,
+            Player::SouthEast => Player::South,
+            Player::SouthWest => Player::West,
+            Player::NorthWest => Player::North,
         }
     }
 }
@@ -62,6 +199,25 @@ impl fmt::Display for Player {
             Player::South => write!(f, "South"),
             Player::East => write!(f, "East"),
             Player::West => write!(f, "West"),
+            Player::NorthEast => write!(f, "NorthEast"),
+            Player::SouthEast => write!(f, "SouthEast"),
+            Player::SouthWest => write!(f, "SouthWest"),
+            Player::NorthWest => write!(f, "NorthWest"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Call {
+    Bid(u32),
+    Pass,
+}
+
+impl fmt::Display for Call {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Call::Bid(amount) => write!(f, "{}", amount),
+            Call::Pass => write!(f, "Pass"),
         }
     }
 }
@@ -94,6 +250,15 @@ impl fmt::Display for GameState {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum HandState {
     WaitingForBid,
+    Bidding {
+        next_to_call: Player,
+        high_bidder: Option<Player>,
+        high_bid: Option<u32>
+    },
+    WidowExposed {
+        bidder: Player,
+        bid_amount: u32
+    },
     WaitingForTrump {
         bidder: Player,
         bid_amount: u32
@@ -110,60 +275,34 @@ pub enum HandState {
     WaitingForTricks {
         bidder: Player,
         bid_amount: u32,
-        trump: Suit,
-        us_meld: Option<u32>,
-        them_meld: Option<u32>
+        trump: Suit
+    },
+    Playing {
+        bidder: Player,
+        bid_amount: u32,
+        trump: Suit
     },
     Completed {
         bidder: Player,
         bid_amount: u32,
-        trump: Suit,
-        us_meld: Option<u32>,
-        them_meld: Option<u32>,
-        us_tricks: Option<u32>,
-        them_tricks: Option<u32>,
-        us_total: Option<i32>,
-        them_total: Option<i32>
+        trump: Suit
     },
-}
-
-impl HandState {
-    pub fn us_meld(self) -> Option<u32> {
-        match self {
-            HandState::WaitingForTricks { us_meld, .. } => us_meld,
-            HandState::Completed { us_meld, .. } => us_meld,
-            _ => None
-        }
-    }
-    
-    pub fn them_meld(self) -> Option<u32> {
-        match self {
-            HandState::WaitingForTricks { them_meld, .. } => them_meld,
-            HandState::Completed { them_meld, .. } => them_meld,
-            _ => None
-        }
-    }
-    
-    pub fn bid_amount(self) -> Option<u32> {
-        match self {
-            HandState::WaitingForTrump { bid_amount, .. } => Some(bid_amount),
-            HandState::WaitingForTricks { bid_amount, .. } => Some(bid_amount),
-            HandState::Completed { bid_amount, .. } => Some(bid_amount),
-            _ => None
-        }
-    }
-    
+    Redealt,
 }
 
 impl fmt::Display for HandState {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
             HandState::WaitingForBid => write!(f, "WaitingForBid"),
+            HandState::Bidding { .. } => write!(f, "Bidding"),
+            HandState::WidowExposed { .. } => write!(f, "WidowExposed"),
             HandState::WaitingForTrump { .. } => write!(f, "WaitingForTrump"),
             HandState::NoMarriage { .. } => write!(f, "NoMarriage"),
             HandState::WaitingForMeld { .. } => write!(f, "WaitingForMeld"),
             HandState::WaitingForTricks { .. } => write!(f, "WaitingForTricks"),
+            HandState::Playing { .. } => write!(f, "Playing"),
             HandState::Completed { .. } => write!(f, "Completed"),
+            HandState::Redealt => write!(f, "Redealt"),
         }
     }
 }
@@ -184,6 +323,51 @@ impl fmt::Display for GameId {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MatchId(pub Uuid);
+
+impl MatchId {
+    pub fn new() -> MatchId {
+        MatchId(Uuid::new_v4())
+    }
+}
+
+impl fmt::Display for MatchId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TournamentId(pub Uuid);
+
+impl TournamentId {
+    pub fn new() -> TournamentId {
+        TournamentId(Uuid::new_v4())
+    }
+}
+
+impl fmt::Display for TournamentId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PlayerId(pub Uuid);
+
+impl PlayerId {
+    pub fn new() -> PlayerId {
+        PlayerId(Uuid::new_v4())
+    }
+}
+
+impl fmt::Display for PlayerId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct HandId(pub Uuid);
 
 impl HandId {
@@ -192,6 +376,12 @@ impl HandId {
     }
 }
 
+impl fmt::Display for HandId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use dashmap::DashMap;
+use dashmap::mapref::entry::Entry;
+use crate::domain::{EventStore, EventStoreError, GameEvent, GameId};
+
+/// A game's events and when they were last appended to.
+struct Stream {
+    events: Vec<GameEvent>,
+    updated_at: DateTime<Utc>,
+}
+
+pub struct InMemoryEventStore {
+    streams: Arc<DashMap<GameId, Stream>>
+}
+
+impl InMemoryEventStore {
+    pub fn new() -> Self {
+        Self {
+            streams: Arc::new(DashMap::new())
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl EventStore for InMemoryEventStore {
+    async fn append(&self, game_id: GameId, expected_version: usize, events: Vec<GameEvent>) -> Result<(), EventStoreError> {
+        let opens_stream = matches!(events.first(), Some(GameEvent::GameStarted { .. }));
+        let conflict = |actual: usize| EventStoreError::Conflict { game_id, expected: expected_version, actual };
+
+        match self.streams.entry(game_id) {
+            Entry::Occupied(_) if opens_stream => Err(EventStoreError::StreamAlreadyExists(game_id)),
+            Entry::Occupied(stream) if stream.get().events.len() != expected_version => Err(conflict(stream.get().events.len())),
+            Entry::Occupied(mut stream) => {
+                let stream = stream.get_mut();
+                stream.events.extend(events);
+                stream.updated_at = Utc::now();
+                Ok(())
+            },
+            Entry::Vacant(_) if opens_stream && expected_version != 0 => Err(conflict(0)),
+            Entry::Vacant(stream) if opens_stream => {
+                stream.insert(Stream { events, updated_at: Utc::now() });
+                Ok(())
+            },
+            Entry::Vacant(_) => Err(EventStoreError::StreamDoesNotExist(game_id)),
+        }
+    }
+
+    async fn load(&self, game_id: GameId) -> Result<Vec<GameEvent>, EventStoreError> {
+        self.streams
+            .get(&game_id)
+            .map(|stream| stream.events.clone())
+            .ok_or(EventStoreError::StreamDoesNotExist(game_id))
+    }
+
+    async fn game_ids(&self) -> Result<Vec<GameId>, EventStoreError> {
+        Ok(self.streams.iter().map(|stream| *stream.key()).collect())
+    }
+
+    async fn updated_at(&self, game_id: GameId) -> Result<DateTime<Utc>, EventStoreError> {
+        self.streams
+            .get(&game_id)
+            .map(|stream| stream.updated_at)
+            .ok_or(EventStoreError::StreamDoesNotExist(game_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Game, Player, Suit};
+
+    #[tokio::test]
+    async fn should_open_a_stream_with_game_started_only() {
+        let store = InMemoryEventStore::new();
+        let game = Game::new(Player::North);
+
+        assert_eq!(
+            store.append(game.id(), 0, vec![GameEvent::AllPassed]).await,
+            Err(EventStoreError::StreamDoesNotExist(game.id()))
+        );
+        store.append(game.id(), 0, vec![GameEvent::started(&game)]).await.unwrap();
+        let opened_at = store.updated_at(game.id()).await.unwrap();
+        assert_eq!(
+            store.append(game.id(), 1, vec![GameEvent::started(&game)]).await,
+            Err(EventStoreError::StreamAlreadyExists(game.id()))
+        );
+
+        store.append(game.id(), 1, vec![GameEvent::AllPassed]).await.unwrap();
+        assert_eq!(store.load(game.id()).await.unwrap().len(), 2);
+        assert_eq!(store.game_ids().await.unwrap(), vec![game.id()]);
+        assert!(store.updated_at(game.id()).await.unwrap() >= opened_at);
+    }
+
+    #[tokio::test]
+    async fn should_accept_only_one_of_two_appends_racing_from_the_same_version() {
+        let store = InMemoryEventStore::new();
+        let game = Game::new(Player::North);
+        store.append(game.id(), 0, vec![GameEvent::started(&game)]).await.unwrap();
+
+        let (first, second) = tokio::join!(
+            store.append(game.id(), 1, vec![GameEvent::AllPassed]),
+            store.append(game.id(), 1, vec![GameEvent::TrumpDeclared { trump: Suit::Spades }]),
+        );
+
+        let conflict = Err(EventStoreError::Conflict { game_id: game.id(), expected: 1, actual: 2 });
+        assert!((first.is_ok() && second == conflict) || (second.is_ok() && first == conflict));
+        assert_eq!(store.load(game.id()).await.unwrap().len(), 2);
+
+        let other = Game::new(Player::East);
+        assert_eq!(
+            store.append(other.id(), 1, vec![GameEvent::started(&other)]).await,
+            Err(EventStoreError::Conflict { game_id: other.id(), expected: 1, actual: 0 })
+        );
+    }
+}
@@ -1,62 +1,63 @@
 use std::sync::Arc;
-use dashmap::DashMap;
-use tokio::sync::Mutex;
-use crate::domain::{Game, GameId, GameRepository, GameRepositoryError};
+use crate::domain::{EventStore, EventStoreError, Game, GameEvent, GameId, GameRepository, GameRepositoryError};
 
+/// Games rebuilt from the events in an event store.
 pub struct InMemoryGameRepository {
-    games: Arc<DashMap<GameId, Game>>
+    events: Arc<dyn EventStore + Send + Sync>
 }
 
 impl InMemoryGameRepository {
-    pub fn new() -> Self {
+    pub fn new(events: Arc<dyn EventStore + Send + Sync>) -> Self {
         Self {
-            games: Arc::new(DashMap::new())
+            events
         }
     }
+
+    fn rebuild(game_id: GameId, events: &[GameEvent]) -> Result<Game, GameRepositoryError> {
+        Game::from_events(events)
+            .map_err(|error| GameRepositoryError::GameRepositoryError(format!("Could not rebuild game {}: {}", game_id, error)))
+    }
 }
 
 #[async_trait::async_trait]
 impl GameRepository for InMemoryGameRepository {
+    /// Every game that can be rebuilt. A stream that cannot is logged and
+    /// left out, so one bad game does not hide the rest.
     async fn  find_all(&self) -> Result<Vec<Game>, GameRepositoryError> {
-        Ok(self.games.iter().map(|game| game.value().clone()).collect())
+        let mut games = Vec::new();
+        for game_id in self.events.game_ids().await.map_err(GameRepositoryError::from)? {
+            let events = self.events.load(game_id).await?;
+            match Self::rebuild(game_id, &events) {
+                Ok(game) => games.push(game),
+                Err(error) => tracing::warn!("Skipping game {}: {}", game_id, error),
+            }
+        }
+        Ok(games)
     }
 
     async fn find_by_id(&self, id: GameId) -> Result<Option<Game>, GameRepositoryError> {
-        if !self.games.contains_key(&id) {
-           return Err(GameRepositoryError::GameDoesNotExist(id));
+        match self.events.load(id).await {
+            Ok(events) => Self::rebuild(id, &events).map(Some),
+            Err(EventStoreError::StreamDoesNotExist(_)) => Ok(None),
+            Err(error) => Err(error.into()),
         }
-        Ok(self.games.get(&id).map(|game| game.value().clone()))
     }
+}
 
-    async fn save(&self, game: Game) -> Result<(), GameRepositoryError> {
-
-        if self.games.contains_key(&game.id()) {
-            let updated_game = {
-                let orig = self.games.get(&game.id()).unwrap();
-
-                orig.value()
-                    .clone()
-                    .with_current_hand(game.current_hand())
-                    .with_state(game.state())
-                    .with_completed_hands(game.completed_hands())
-
-            };
-
-            self.games.insert(game.id().clone(), updated_game);
-            tracing::info!("Updated game: {:#?}", self.find_by_id(game.id()).await?);
-        } else {
-            self.games.insert(game.id().clone(), game.clone());
-            tracing::info!("Inserted game: {:#?}", self.find_by_id(game.id()).await?);
+impl From<EventStoreError> for GameRepositoryError {
+    fn from(error: EventStoreError) -> Self {
+        match error {
+            EventStoreError::StreamDoesNotExist(id) => GameRepositoryError::GameDoesNotExist(id),
+            error => GameRepositoryError::GameRepositoryError(error.to_string()),
         }
-
-        Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::domain::{Game, GameId, Player, GameState};
+    use crate::domain::{Player, Suit};
+    use crate::infrastructure::InMemoryEventStore;
     use uuid::Uuid;
 
     fn sample_game() -> Game {
@@ -64,36 +65,72 @@ mod tests {
         Game::new(Player::North)
     }
 
+    fn repository() -> (InMemoryGameRepository, Arc<InMemoryEventStore>) {
+        let store = Arc::new(InMemoryEventStore::new());
+        (InMemoryGameRepository::new(store.clone()), store)
+    }
+
     #[tokio::test]
     async fn save_and_find_by_id() {
-        let repo = InMemoryGameRepository::new();
+        let (repo, store) = repository();
         let game = sample_game();
-        repo.save(game.clone()).await.unwrap();
+        store.append(game.id(), 0, vec![GameEvent::started(&game)]).await.unwrap();
         let found = repo.find_by_id(game.id()).await.unwrap();
-        assert_eq!(found, Some(game));
+        assert_eq!(found, Some(game.with_version(1)));
     }
 
     #[tokio::test]
     async fn find_all_returns_all_saved_games() {
-        let repo = InMemoryGameRepository::new();
+        let (repo, store) = repository();
         let game1 = sample_game();
         let game2 = sample_game();
-        repo.save(game1.clone()).await.unwrap();
-        repo.save(game2.clone()).await.unwrap();
+        store.append(game1.id(), 0, vec![GameEvent::started(&game1)]).await.unwrap();
+        store.append(game2.id(), 0, vec![GameEvent::started(&game2)]).await.unwrap();
         let all = repo.find_all().await.unwrap();
-        assert!(all.contains(&game1));
-        assert!(all.contains(&game2));
+        assert!(all.contains(&game1.with_version(1)));
+        assert!(all.contains(&game2.with_version(1)));
+    }
+
+    #[tokio::test]
+    async fn find_all_skips_streams_that_cannot_be_rebuilt() {
+        let (repo, store) = repository();
+        let valid = sample_game();
+        let corrupt = sample_game();
+        store.append(valid.id(), 0, vec![GameEvent::started(&valid)]).await.unwrap();
+        store.append(corrupt.id(), 0, vec![GameEvent::started(&corrupt), GameEvent::TrumpDeclared { trump: Suit::Spades }]).await.unwrap();
+
+        let all = repo.find_all().await.unwrap();
+
+        assert_eq!(all, vec![valid.with_version(1)]);
+        assert!(repo.find_by_id(corrupt.id()).await.is_err());
     }
 
     #[tokio::test]
     async fn find_by_id_nonexistent_returns_none() {
-        let repo = InMemoryGameRepository::new();
+        let (repo, _) = repository();
         let id = GameId(Uuid::new_v4());
         let game = repo.find_by_id(id).await;
-        
-        assert!(game.is_err());
-        
-        assert_eq!(game.unwrap_err(), GameRepositoryError::GameDoesNotExist(id));
+
+        assert_eq!(game, Ok(None));
     }
 
+    #[tokio::test]
+    async fn should_rebuild_game_from_appended_changes() {
+        let (repo, store) = repository();
+        let game = sample_game();
+        store.append(game.id(), 0, vec![GameEvent::started(&game)]).await.unwrap();
+
+        let played = game
+            .start_new_hand()
+            .unwrap()
+            .record_bid(Player::North, 51)
+            .unwrap()
+            .declare_trump(Suit::Spades)
+            .unwrap();
+        store.append(game.id(), 1, played.changes().to_vec()).await.unwrap();
+
+        let found = repo.find_by_id(game.id()).await.unwrap().unwrap();
+        assert_eq!(found.current_hand(), played.current_hand());
+        assert!(found.changes().is_empty());
+    }
 }
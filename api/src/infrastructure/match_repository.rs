@@ -0,0 +1,83 @@
+use std::sync::Arc;
+use dashmap::DashMap;
+use dashmap::mapref::entry::Entry;
+use crate::domain::{GameId, Match, MatchId, MatchRepository, MatchRepositoryError};
+
+pub struct InMemoryMatchRepository {
+    matches: Arc<DashMap<MatchId, Match>>
+}
+
+impl InMemoryMatchRepository {
+    pub fn new() -> Self {
+        Self {
+            matches: Arc::new(DashMap::new())
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl MatchRepository for InMemoryMatchRepository {
+    async fn find_by_id(&self, id: MatchId) -> Result<Option<Match>, MatchRepositoryError> {
+        if !self.matches.contains_key(&id) {
+            return Err(MatchRepositoryError::MatchDoesNotExist(id));
+        }
+        Ok(self.matches.get(&id).map(|game_match| game_match.value().clone()))
+    }
+
+    async fn find_by_game(&self, game_id: GameId) -> Result<Option<Match>, MatchRepositoryError> {
+        Ok(self.matches
+            .iter()
+            .find(|game_match| game_match.value().games().contains(&game_id))
+            .map(|game_match| game_match.value().clone()))
+    }
+
+    async fn save(&self, game_match: Match) -> Result<(), MatchRepositoryError> {
+        let expected = game_match.version();
+        let conflict = |actual: usize| MatchRepositoryError::Conflict { match_id: game_match.id(), expected, actual };
+
+        match self.matches.entry(game_match.id()) {
+            Entry::Occupied(stored) if stored.get().version() != expected => Err(conflict(stored.get().version())),
+            Entry::Vacant(_) if expected != 0 => Err(conflict(0)),
+            Entry::Occupied(mut stored) => {
+                stored.insert(game_match.clone().with_version(expected + 1));
+                Ok(())
+            },
+            Entry::Vacant(slot) => {
+                slot.insert(game_match.clone().with_version(expected + 1));
+                Ok(())
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Game, Player};
+
+    #[tokio::test]
+    async fn save_and_find_by_game() {
+        let repo = InMemoryMatchRepository::new();
+        let game = Game::new(Player::North);
+        let game_match = Match::new(&game, 2).unwrap();
+        repo.save(game_match.clone()).await.unwrap();
+        let saved = game_match.with_version(1);
+
+        assert_eq!(repo.find_by_id(saved.id()).await.unwrap(), Some(saved.clone()));
+        assert_eq!(repo.find_by_game(game.id()).await.unwrap(), Some(saved));
+        assert_eq!(repo.find_by_game(GameId::new()).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn save_refuses_a_stale_copy() {
+        let repo = InMemoryMatchRepository::new();
+        let game_match = Match::new(&Game::new(Player::North), 2).unwrap();
+        repo.save(game_match.clone()).await.unwrap();
+
+        let loaded = repo.find_by_id(game_match.id()).await.unwrap().unwrap();
+        repo.save(loaded.clone()).await.unwrap();
+
+        assert_eq!(repo.save(loaded).await, Err(MatchRepositoryError::Conflict { match_id: game_match.id(), expected: 1, actual: 2 }));
+        assert_eq!(repo.save(game_match.clone()).await, Err(MatchRepositoryError::Conflict { match_id: game_match.id(), expected: 0, actual: 2 }));
+    }
+}
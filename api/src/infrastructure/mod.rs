@@ -3,7 +3,15 @@ use dashmap::DashMap;
 use crate::domain::{Game, GameId, GameRepository, GameRepositoryError, Hand, HandId, HandRepository, HandRepositoryError};
 
 mod game_repository;
+mod event_store;
 mod hand_repository;
+mod rating_repository;
+mod match_repository;
+mod tournament_repository;
 
 pub use game_repository::{InMemoryGameRepository};
+pub use event_store::InMemoryEventStore;
 pub use hand_repository::{InMemoryHandRepository};
+pub use rating_repository::InMemoryRatingRepository;
+pub use match_repository::InMemoryMatchRepository;
+pub use tournament_repository::InMemoryTournamentRepository;
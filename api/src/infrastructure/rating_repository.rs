@@ -0,0 +1,55 @@
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use crate::domain::{RatingRepository, RatingRepositoryError, Ratings};
+
+pub struct InMemoryRatingRepository {
+    ratings: Arc<Mutex<Ratings>>
+}
+
+impl InMemoryRatingRepository {
+    pub fn new() -> Self {
+        Self {
+            ratings: Arc::new(Mutex::new(Ratings::default()))
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl RatingRepository for InMemoryRatingRepository {
+    async fn load(&self) -> Result<Ratings, RatingRepositoryError> {
+        Ok(self.ratings.lock().await.clone())
+    }
+
+    async fn save(&self, ratings: Ratings) -> Result<(), RatingRepositoryError> {
+        let mut stored = self.ratings.lock().await;
+        if stored.version() != ratings.version() {
+            return Err(RatingRepositoryError::Conflict { expected: ratings.version(), actual: stored.version() });
+        }
+        let version = ratings.version() + 1;
+        *stored = ratings.with_version(version);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn save_and_load() {
+        let repo = InMemoryRatingRepository::new();
+        assert_eq!(repo.load().await.unwrap(), Ratings::default());
+
+        repo.save(Ratings::replay([])).await.unwrap();
+        assert_eq!(repo.load().await.unwrap(), Ratings::default().with_version(1));
+    }
+
+    #[tokio::test]
+    async fn save_refuses_stale_ratings() {
+        let repo = InMemoryRatingRepository::new();
+        let loaded = repo.load().await.unwrap();
+        repo.save(loaded.clone()).await.unwrap();
+
+        assert_eq!(repo.save(loaded).await, Err(RatingRepositoryError::Conflict { expected: 0, actual: 1 }));
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+use dashmap::DashMap;
+use crate::domain::{Tournament, TournamentId, TournamentRepository, TournamentRepositoryError};
+
+pub struct InMemoryTournamentRepository {
+    tournaments: Arc<DashMap<TournamentId, Tournament>>
+}
+
+impl InMemoryTournamentRepository {
+    pub fn new() -> Self {
+        Self {
+            tournaments: Arc::new(DashMap::new())
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TournamentRepository for InMemoryTournamentRepository {
+    async fn find_by_id(&self, id: TournamentId) -> Result<Option<Tournament>, TournamentRepositoryError> {
+        if !self.tournaments.contains_key(&id) {
+            return Err(TournamentRepositoryError::TournamentDoesNotExist(id));
+        }
+        Ok(self.tournaments.get(&id).map(|tournament| tournament.value().clone()))
+    }
+
+    async fn save(&self, tournament: Tournament) -> Result<(), TournamentRepositoryError> {
+        self.tournaments.insert(tournament.id(), tournament);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{PlayerProfile, RuleSet};
+
+    #[tokio::test]
+    async fn save_and_find_by_id() {
+        let repo = InMemoryTournamentRepository::new();
+        let players = ["Ann", "Bob", "Cat", "Dan"].map(PlayerProfile::new).to_vec();
+        let (tournament, _) = Tournament::new("Spring", players, RuleSet::default(), None).unwrap();
+        repo.save(tournament.clone()).await.unwrap();
+
+        assert_eq!(repo.find_by_id(tournament.id()).await.unwrap(), Some(tournament));
+        assert!(repo.find_by_id(TournamentId::new()).await.is_err());
+    }
+}
@@ -3,7 +3,7 @@ use axum::Router;
 use axum::routing::get;
 use tokio::sync::Mutex;
 use controller::router;
-use crate::application::{DeclareTrump, GetCompletedHands, GetCurrentHand, GetRunningTotal, RecordBid, RecordMeld, RecordTricks, StartNewGame, StartNewHand};
+use crate::application::{DeclareTrump, GetCompletedHands, GetCurrentHand, GetGame, GetLeaderboard, GetPlayerStats, GetRatings, GetRunningTotal, GetTimeline, ListGames, RecomputeRatings, CreateMatch, GetMatch, CreateTournament, GetTournament, RecordAllPass, RecordBid, RecordCall, PlayCard, RecordCounters, RecordWidow, SubstitutePlayer, UndoAction, RedoAction, EditCompletedHand, RecordMeld, RecordTricks, StartNewGame, StartNewHand, StartPlay};
 use crate::domain::GameRepository;
 use crate::infrastructure::InMemoryGameRepository;
 use tower_http::trace::TraceLayer;
@@ -23,12 +23,33 @@ struct AppState {
     pub start_game: Arc<StartNewGame>,
     pub start_hand: Arc<StartNewHand>,
     pub record_bid: Arc<RecordBid>,
+    pub record_call: Arc<RecordCall>,
+    pub record_all_pass: Arc<RecordAllPass>,
     pub declare_trump: Arc<DeclareTrump>,
     pub record_meld: Arc<RecordMeld>,
     pub record_tricks: Arc<RecordTricks>,
+    pub record_counters: Arc<RecordCounters>,
+    pub start_play: Arc<StartPlay>,
+    pub play_card: Arc<PlayCard>,
+    pub record_widow: Arc<RecordWidow>,
+    pub substitute_player: Arc<SubstitutePlayer>,
+    pub undo_action: Arc<UndoAction>,
+    pub redo_action: Arc<RedoAction>,
+    pub edit_completed_hand: Arc<EditCompletedHand>,
     pub get_completed_hands: Arc<GetCompletedHands>,
     pub get_current_hand: Arc<GetCurrentHand>,
-    pub get_running_total: Arc<GetRunningTotal>
+    pub get_running_total: Arc<GetRunningTotal>,
+    pub get_timeline: Arc<GetTimeline>,
+    pub get_game: Arc<GetGame>,
+    pub list_games: Arc<ListGames>,
+    pub get_player_stats: Arc<GetPlayerStats>,
+    pub get_leaderboard: Arc<GetLeaderboard>,
+    pub get_ratings: Arc<GetRatings>,
+    pub recompute_ratings: Arc<RecomputeRatings>,
+    pub create_match: Arc<CreateMatch>,
+    pub get_match: Arc<GetMatch>,
+    pub create_tournament: Arc<CreateTournament>,
+    pub get_tournament: Arc<GetTournament>,
 }
 
 struct App {
@@ -61,12 +82,37 @@ async fn main() {
 
 fn print_routes() {
     println!("📍 Configured Routes:");
+    println!("  GET  /api/games?state=&sort=updated_at|created_at&order=desc|asc&page=&per_page=");
     println!("  POST /api/games/");
-    println!("  GET  /api/games/:id/");
+    println!("  GET  /api/games/:id/?as_of_hand=&after=bid|meld|tricks|...");
+    println!("  GET  /api/games/:id/completed_hands");
     println!("  POST /api/games/:id/start_hand");
     println!("  POST /api/games/:id/record_bid");
+    println!("  POST /api/games/:id/record_call");
+    println!("  POST /api/games/:id/all_pass");
+    println!("  POST /api/games/:id/record_widow");
     println!("  POST /api/games/:id/declare_trump");
     println!("  POST /api/games/:id/record_meld");
     println!("  POST /api/games/:id/record_tricks");
+    println!("  POST /api/games/:id/record_counters");
+    println!("  POST /api/games/:id/start_play");
+    println!("  POST /api/games/:id/play_card");
+    println!("  POST /api/games/:id/substitute");
+    println!("  POST /api/games/:id/undo");
+    println!("  POST /api/games/:id/redo");
+    println!("  PUT  /api/games/:id/hands/:hand_id");
     println!("  GET  /api/games/:id/running_total");
+    println!("  GET  /api/games/:id/timeline");
+    println!("  GET  /api/v2/games/:id/");
+    println!("  GET  /api/v2/games/:id/running_total");
+    println!("  GET  /api/stats/players?from=&to=");
+    println!("  GET  /api/leaderboard?by=partnership|player&sort=win_rate|wins|margin|games_played|name");
+    println!("  GET  /api/ratings");
+    println!("  POST /api/ratings/recompute");
+    println!("  POST /api/matches/");
+    println!("  GET  /api/matches/:id/");
+    println!("  GET  /api/matches/:id/games");
+    println!("  POST /api/tournaments/");
+    println!("  GET  /api/tournaments/:id/");
+    println!("  GET  /api/tournaments/:id/standings");
 }
\ No newline at end of file